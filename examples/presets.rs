@@ -0,0 +1,144 @@
+// cargo run --example presets --target aarch64-apple-darwin
+// cargo run --example presets --target x86_64-apple-darwin
+
+extern crate asr;
+extern crate hollowknight_autosplit_wasm;
+extern crate std;
+
+use asr::settings::{Map, Value};
+use hollowknight_autosplit_wasm::presets::SplitsPreset;
+use hollowknight_autosplit_wasm::splits::Split;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+use ugly_widget::radio_button::{RadioButtonOption, RadioButtonOptions};
+
+fn main() -> io::Result<()> {
+    let presets_dir = Path::new(file!()).parent().unwrap().join("presets");
+    fs::create_dir_all(&presets_dir)?;
+
+    for o in SplitsPreset::radio_button_options() {
+        let RadioButtonOption {
+            value,
+            key,
+            description,
+            ..
+        } = o;
+        let (Some(splits), Some(settings_map)) = (value.splits(), value.asr_settings_map()) else {
+            continue;
+        };
+        let file = File::create(presets_dir.join(format!("{}.lss", key)))?;
+        let mut writer = BufWriter::new(file);
+        write_lss(&mut writer, description, splits, &settings_map)?;
+        writer.flush()?;
+    }
+    Ok(())
+}
+
+fn write_lss<W: Write>(
+    w: &mut W,
+    category: &str,
+    splits: &[Split],
+    settings_map: &Map,
+) -> io::Result<()> {
+    writeln!(w, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+    writeln!(w, r#"<Run version="1.8.0">"#)?;
+    writeln!(w, "  <GameIcon />")?;
+    writeln!(w, "  <GameName>Hollow Knight</GameName>")?;
+    writeln!(w, "  <CategoryName>{}</CategoryName>", xml_escape(category))?;
+    writeln!(w, "  <Offset>00:00:00</Offset>")?;
+    writeln!(w, "  <AttemptCount>0</AttemptCount>")?;
+    writeln!(w, "  <AttemptHistory />")?;
+    writeln!(w, "  <Segments>")?;
+    // the first split starts the timer, so it is not a segment
+    for split in splits.iter().skip(1) {
+        writeln!(w, "    <Segment>")?;
+        writeln!(w, "      <Name>{}</Name>", xml_escape(segment_name(split)))?;
+        writeln!(w, "      <Icon />")?;
+        writeln!(w, "      <SplitTimes>")?;
+        writeln!(w, r#"        <SplitTime name="Personal Best" />"#)?;
+        writeln!(w, "      </SplitTimes>")?;
+        writeln!(w, "      <BestSegmentTime />")?;
+        writeln!(w, "      <SegmentHistory />")?;
+        writeln!(w, "    </Segment>")?;
+    }
+    writeln!(w, "  </Segments>")?;
+    writeln!(w, "  <AutoSplitterSettings>")?;
+    writeln!(w, "    <Version>1.0.0.0</Version>")?;
+    writeln!(w, "    <ScriptPath />")?;
+    writeln!(w, "    <CustomSettings>")?;
+    write_settings_map(w, 6, settings_map)?;
+    writeln!(w, "    </CustomSettings>")?;
+    writeln!(w, "  </AutoSplitterSettings>")?;
+    writeln!(w, "</Run>")?;
+    Ok(())
+}
+
+/// Writes each entry of the map as a `Setting` element, in the same format
+/// that the auto splitter reads back from `CustomSettings`
+fn write_settings_map<W: Write>(w: &mut W, indent: usize, m: &Map) -> io::Result<()> {
+    for key in m.keys() {
+        if let Some(v) = m.get(&key) {
+            write_setting(w, indent, Some(&key), &v)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_setting<W: Write>(
+    w: &mut W,
+    indent: usize,
+    id: Option<&str>,
+    v: &Value,
+) -> io::Result<()> {
+    let pad = " ".repeat(indent);
+    let id = id.map_or(String::new(), |id| format!(r#" id="{}""#, xml_escape(id)));
+    if let Some(b) = v.get_bool() {
+        writeln!(w, r#"{}<Setting{} type="bool">{}</Setting>"#, pad, id, b)
+    } else if let Some(i) = v.get_i64() {
+        writeln!(w, r#"{}<Setting{} type="i64">{}</Setting>"#, pad, id, i)
+    } else if let Some(f) = v.get_f64() {
+        writeln!(w, r#"{}<Setting{} type="f64">{}</Setting>"#, pad, id, f)
+    } else if let Some(s) = v.get_string() {
+        writeln!(
+            w,
+            r#"{}<Setting{} type="string" value="{}" />"#,
+            pad,
+            id,
+            xml_escape(&s)
+        )
+    } else if let Some(l) = v.get_list() {
+        writeln!(w, r#"{}<Setting{} type="list">"#, pad, id)?;
+        for e in l.iter() {
+            write_setting(w, indent + 2, None, &e)?;
+        }
+        writeln!(w, "{}</Setting>", pad)
+    } else if let Some(m) = v.get_map() {
+        writeln!(w, r#"{}<Setting{} type="map">"#, pad, id)?;
+        write_settings_map(w, indent + 2, &m)?;
+        writeln!(w, "{}</Setting>", pad)
+    } else {
+        Ok(())
+    }
+}
+
+/// The split's description without the category in parentheses,
+/// such as `Hornet 1` from `Hornet 1 (Boss)`.
+fn segment_name(split: &Split) -> &'static str {
+    let description = Split::radio_button_options()
+        .into_iter()
+        .find_map(|o| (&o.value == split).then_some(o.description))
+        .unwrap_or_default();
+    description
+        .rsplit_once(" (")
+        .map_or(description, |(name, _)| name)
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,142 @@
+// cargo run --release --example tick_bench --target aarch64-apple-darwin
+// cargo run --release --example tick_bench --target x86_64-apple-darwin
+// Optionally pass a budget in nanoseconds per tick, to fail if the tick path is slower:
+// cargo run --release --example tick_bench --target x86_64-apple-darwin -- 2000
+
+extern crate hollowknight_autosplit_wasm;
+extern crate std;
+
+use hollowknight_autosplit_wasm::{PlayerDataStore, SceneName, SceneStore};
+use std::{
+    env, fs,
+    hint::black_box,
+    io,
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+    vec::Vec,
+};
+
+/// Number of times to replay the trace
+const REPLAYS: usize = 100;
+
+/// Player data a run with a few splits checks every tick,
+/// from the game state to the splits' own keys
+const I32_KEYS: [&str; 6] = [
+    "game_state",
+    "heart_pieces",
+    "vessel_fragments",
+    "max_health_base",
+    "grubs_collected",
+    "dream_orbs",
+];
+const BOOL_KEYS: [&str; 6] = [
+    "has_dash",
+    "has_wall_jump",
+    "killed_false_knight",
+    "killed_hornet_1",
+    "sly_shop_finished_on_entry",
+    "opened_city_gate",
+];
+
+/// Scenes the splits compare against, like `ss.pair().current == "..."`
+const SPLIT_SCENES: [&str; 4] = ["Town", "Crossroads_01", "Fungus1_04", "Waterways_15"];
+
+/// The scene name and next scene name read on one tick
+type Tick = (Option<SceneName>, Option<SceneName>);
+
+/// Everything the tick path keeps from tick to tick,
+/// apart from reading the game's memory
+struct Stores {
+    scenes: SceneStore,
+    player_data: PlayerDataStore,
+}
+
+fn main() -> io::Result<ExitCode> {
+    let trace_path = Path::new(file!()).parent().unwrap().join("tick_trace.txt");
+    let ticks = read_trace(&fs::read_to_string(trace_path)?);
+    let budget = env::args().nth(1).and_then(|a| a.parse::<f64>().ok());
+
+    let (elapsed, transitions) = replay(&ticks);
+
+    let per_tick = elapsed.as_nanos() as f64 / (ticks.len() * REPLAYS) as f64;
+    println!(
+        "{} ticks, {} transitions per replay",
+        ticks.len(),
+        transitions / REPLAYS
+    );
+    println!("{:.1} ns per tick", per_tick);
+
+    if let Some(budget) = budget {
+        if budget < per_tick {
+            println!("over budget of {} ns per tick", budget);
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn read_trace(trace: &str) -> Vec<Tick> {
+    let mut ticks = Vec::new();
+    for line in trace.lines() {
+        if line.starts_with('#') || line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        let [n, curr, next] = fields[..] else {
+            panic!("bad trace line: {:?}", line);
+        };
+        let n: usize = n.parse().expect("number of ticks");
+        let tick = (SceneName::new(curr), SceneName::new(next));
+        ticks.extend(std::iter::repeat_n(tick, n));
+    }
+    ticks
+}
+
+/// One tick of the tick path, given the scene names read:
+/// scene transitions, loaded scenes, and the player data the splits look at
+fn tick(stores: &mut Stores, &(curr, next): &Tick, transitions: usize) -> bool {
+    let transition = stores.scenes.transition_on(curr, next);
+    if transition {
+        stores.player_data.clean_on_entry();
+    }
+    stores.scenes.update_loaded_scenes(curr);
+    let non_menu = curr.is_some_and(|c| !c.is_menu());
+    // the values change a little with each transition, like items picked up along the way
+    let value = (transitions % 5) as i32;
+    for key in I32_KEYS {
+        black_box(stores.player_data.store_changed_i32(key, value, non_menu));
+    }
+    for key in BOOL_KEYS {
+        black_box(
+            stores
+                .player_data
+                .store_changed_bool(key, value == 0, non_menu),
+        );
+    }
+    let pair = stores.scenes.pair();
+    for scene in SPLIT_SCENES {
+        black_box(pair.current == scene || stores.scenes.is_scene_loaded(scene));
+    }
+    transition
+}
+
+/// Replays the trace through fresh stores each time,
+/// and returns the total time and number of transitions
+fn replay(ticks: &[Tick]) -> (Duration, usize) {
+    let mut transitions = 0;
+    let start = Instant::now();
+    for _ in 0..REPLAYS {
+        let mut stores = Stores {
+            scenes: SceneStore::new(),
+            player_data: PlayerDataStore::new(),
+        };
+        for t in ticks {
+            if tick(&mut stores, black_box(t), transitions) {
+                transitions += 1;
+            }
+        }
+        black_box(stores.scenes.pair().current.len());
+    }
+    (start.elapsed(), transitions)
+}
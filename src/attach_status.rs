@@ -0,0 +1,112 @@
+use alloc::format;
+use alloc::vec::Vec;
+
+use asr::timer::TimerState;
+use asr::Process;
+
+use crate::hollow_knight_memory::GameManagerFinder;
+use crate::timer::Timer;
+
+/// Number of consecutive ticks that the health check can fail before
+/// giving up on the current module and image and detecting them again
+const TICKS_UNHEALTHY_REATTACH: usize = 0x400;
+
+/// The attach-status custom variable, so runners know why splits aren't happening
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttachStatus {
+    NotAttached,
+    Attaching,
+    Attached,
+    PointersFailing,
+}
+
+impl AttachStatus {
+    pub fn set_variable(&self) {
+        let s = match self {
+            AttachStatus::NotAttached => "not attached",
+            AttachStatus::Attaching => "attaching",
+            AttachStatus::Attached => "attached",
+            AttachStatus::PointersFailing => "pointers failing",
+        };
+        asr::timer::set_variable("attach status", s);
+    }
+}
+
+/// Monitors pointers that should always resolve while attached,
+/// to detect when the module and image need to be detected again.
+pub struct AttachHealth {
+    failing_ticks: usize,
+    failing: Vec<&'static str>,
+}
+
+impl AttachHealth {
+    pub fn new() -> AttachHealth {
+        AttachHealth {
+            failing_ticks: 0,
+            failing: Vec::new(),
+        }
+    }
+
+    /// Returns true when the pointers have been failing for long enough
+    /// that the module and image should be detected again.
+    pub fn update(&mut self, process: &Process, game_manager_finder: &GameManagerFinder) -> bool {
+        let failing = game_manager_finder.failing_pointer_paths(process);
+        if failing.is_empty() {
+            if 0 < self.failing_ticks {
+                asr::print_message("AttachHealth: pointers recovered");
+                AttachStatus::Attached.set_variable();
+            }
+            self.failing_ticks = 0;
+            self.failing = failing;
+            return false;
+        }
+        if self.failing_ticks == 0 || failing != self.failing {
+            asr::print_message(&format!("AttachHealth: pointers failing: {:?}", failing));
+            AttachStatus::PointersFailing.set_variable();
+        }
+        self.failing_ticks += 1;
+        self.failing = failing;
+        TICKS_UNHEALTHY_REATTACH <= self.failing_ticks
+    }
+
+    pub fn failing(&self) -> &[&'static str] {
+        &self.failing
+    }
+}
+
+/// Remembers the segment where the game closed or crashed during a run,
+/// and publishes it as the crashed custom variable until that segment is over.
+pub struct CrashRecord {
+    segment: Option<usize>,
+}
+
+impl CrashRecord {
+    pub fn new() -> CrashRecord {
+        asr::timer::set_variable("crashed", "");
+        CrashRecord { segment: None }
+    }
+
+    /// Pauses game time when the game process is lost,
+    /// and marks the current segment as crashed if a run is in progress.
+    pub fn process_lost(&mut self, timer: &Timer) {
+        asr::timer::pause_game_time();
+        if asr::timer::state() != TimerState::Running {
+            return;
+        }
+        let i = timer.i();
+        asr::print_message(&format!(
+            "Game closed during segment {}, game time paused",
+            i
+        ));
+        asr::timer::set_variable("crashed", &format!("segment {}", i));
+        self.segment = Some(i);
+    }
+
+    /// Clears the crashed variable once the timer moves on from the crashed segment
+    pub fn update(&mut self, timer: &Timer) {
+        if self.segment.is_some_and(|i| i != timer.i()) {
+            asr::timer::set_variable("crashed", "");
+            self.segment = None;
+        }
+    }
+}
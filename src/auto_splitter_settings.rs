@@ -7,7 +7,7 @@ use core::str;
 use roxmltree::Node;
 #[cfg(not(target_os = "unknown"))]
 use std::path::Path;
-use ugly_widget::radio_button::options_normalize;
+use ugly_widget::radio_button::{options_str, options_value};
 
 #[cfg(not(target_os = "unknown"))]
 use crate::file;
@@ -25,6 +25,19 @@ pub fn this_script_name() -> &'static str {
 
 // --------------------------------------------------------
 
+/// The version of the settings layout that this script reads and writes.
+///
+/// Increment this and add a migration to `SETTINGS_MIGRATIONS` whenever
+/// renaming options or restructuring keys would break saved layouts.
+pub const SETTINGS_VERSION: i64 = 1;
+
+/// `SETTINGS_MIGRATIONS[v]` migrates settings from version `v` to `v + 1`,
+/// producing true if it changed anything.
+static SETTINGS_MIGRATIONS: [fn(&asr::settings::Map) -> bool; SETTINGS_VERSION as usize] =
+    [settings_migrate_split_aliases];
+
+// --------------------------------------------------------
+
 pub async fn wait_asr_settings_init() -> asr::settings::Map {
     let settings1 = asr::settings::Map::load();
     let this_script = this_script_name();
@@ -96,9 +109,10 @@ fn asr_settings_from_xml_nodes(xml_nodes: Vec<Node>) -> Option<asr::settings::Ma
     }
 }
 
+/// Stamps the script name, then runs every migration from the
+/// `settings_version` in the map up to `SETTINGS_VERSION`.
+/// Produces Some if anything changed.
 fn asr_settings_normalize(m: &asr::settings::Map) -> Option<()> {
-    let old_splits = m.get("splits")?.get_list()?;
-    let new_splits = asr::settings::List::new();
     let mut changed = false;
     let this_script = this_script_name();
     if !m
@@ -108,21 +122,79 @@ fn asr_settings_normalize(m: &asr::settings::Map) -> Option<()> {
         changed = true;
         m.insert("script_name", this_script);
     }
+    let old_version = asr_settings_version(m);
+    if SETTINGS_VERSION < old_version {
+        asr::print_message(&format!(
+            "warning: settings_version {} is newer than this script's {}",
+            old_version, SETTINGS_VERSION
+        ));
+        return changed.then_some(());
+    }
+    for (v, migrate) in SETTINGS_MIGRATIONS
+        .iter()
+        .enumerate()
+        .skip(old_version as usize)
+    {
+        if migrate(m) {
+            changed = true;
+            asr::print_message(&format!(
+                "Settings migrated from version {} to {}",
+                v,
+                v + 1
+            ));
+        }
+    }
+    // Hand-edited settings can have aliases again after they were migrated
+    if settings_migrate_split_aliases(m) {
+        changed = true;
+    }
+    if old_version != SETTINGS_VERSION {
+        m.insert("settings_version", SETTINGS_VERSION);
+        changed = true;
+    }
+    changed.then_some(())
+}
+
+/// Settings from before `settings_version` existed are version 0.
+fn asr_settings_version(m: &asr::settings::Map) -> i64 {
+    m.get("settings_version")
+        .and_then(|v| v.get_i64())
+        .unwrap_or(0)
+        .max(0)
+}
+
+/// Migrates split aliases in `splits` and `splits_{i}_item` to their current keys.
+///
+/// This also runs on every load at the current version,
+/// since it leaves settings without aliases unchanged.
+fn settings_migrate_split_aliases(m: &asr::settings::Map) -> bool {
+    let Some(old_splits) = m.get("splits").and_then(|v| v.get_list()) else {
+        return false;
+    };
+    let new_splits = asr::settings::List::new();
+    let mut changed = false;
     for (i, old_split) in old_splits.iter().enumerate() {
-        let old_string = old_split.get_string()?;
-        let new_string = options_normalize::<Split>(&old_string);
-        new_splits.push(new_string.as_str());
+        let Some(old_string) = old_split.get_string() else {
+            new_splits.push(&old_split);
+            continue;
+        };
+        let new_string = split_key_normalize(&old_string);
+        new_splits.push(new_string);
         if old_string != new_string {
             changed = true;
-            m.insert(&format!("splits_{}_item", i), new_string.as_str());
+            m.insert(&format!("splits_{}_item", i), new_string);
         }
     }
     if changed {
         m.insert("splits", new_splits);
-        Some(())
-    } else {
-        None
     }
+    changed
+}
+
+/// Rewrites an alias to the current key for the same split,
+/// leaving unknown names unchanged.
+fn split_key_normalize(old: &str) -> &str {
+    options_value::<Split>(old).map_or(old, |s| options_str(&s))
 }
 
 #[cfg(not(target_os = "unknown"))]
@@ -263,3 +335,153 @@ fn maybe_asr_settings_list_merge(
 }
 
 // --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::Path;
+
+    use super::*;
+
+    /// Collects the raw split names from auto splitter settings nodes,
+    /// either the `splits` list of asr settings, or the `Split` elements of legacy settings.
+    fn split_names_from_xml_nodes(xml_nodes: &[Node]) -> Vec<String> {
+        if any_xml_nodes_from_asr(xml_nodes) {
+            xml_nodes
+                .iter()
+                .filter(|n| n.has_tag_name("CustomSettings"))
+                .flat_map(|n| n.children())
+                .filter(|n| n.attribute("id") == Some("splits"))
+                .flat_map(|n| n.children())
+                .filter_map(|n| n.attribute("value"))
+                .map(|s| s.to_string())
+                .collect()
+        } else {
+            xml_nodes
+                .iter()
+                .flat_map(|n| n.descendants())
+                .filter(|n| n.has_tag_name("Split") || n.has_tag_name("AutosplitStartRuns"))
+                .filter_map(|n| n.text())
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        }
+    }
+
+    fn assert_split_names_migrate(source: &str, names: &[String]) {
+        for name in names {
+            let key = split_key_normalize(name);
+            assert!(
+                options_value::<Split>(key).is_some(),
+                "{}: unknown split {:?}",
+                source,
+                name
+            );
+            assert_eq!(split_key_normalize(key), key, "{}: {:?}", source, name);
+        }
+    }
+
+    #[test]
+    fn splits_fixtures_migrate() {
+        let splits_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("splits");
+        let mut checked = 0;
+        for category in fs::read_dir(splits_dir).unwrap() {
+            for entry in fs::read_dir(category.unwrap().path()).unwrap() {
+                let path = entry.unwrap().path();
+                if !path.extension().is_some_and(|e| e == "lss" || e == "lsl") {
+                    continue;
+                }
+                let text = fs::read_to_string(&path).unwrap();
+                let d = roxmltree::Document::parse(&text).unwrap();
+                let Some(xml_nodes) = xml_find_auto_splitter_settings(d.root_element()) else {
+                    continue;
+                };
+                let names = split_names_from_xml_nodes(&xml_nodes);
+                assert_split_names_migrate(&path.to_string_lossy(), &names);
+                checked += names.len();
+            }
+        }
+        assert!(0 < checked);
+    }
+
+    #[test]
+    fn auto_splitter_settings_txt_migrates() {
+        let wrapped = format!(
+            "<AutoSplitterSettings>{}</AutoSplitterSettings>",
+            include_str!("AutoSplitterSettings.txt")
+        );
+        let d = roxmltree::Document::parse(&wrapped).unwrap();
+        let xml_nodes: Vec<Node> = d.root_element().children().collect();
+        let names = split_names_from_xml_nodes(&xml_nodes);
+        assert!(!names.is_empty());
+        assert_split_names_migrate("AutoSplitterSettings.txt", &names);
+    }
+
+    fn get_string(m: &asr::settings::Map, key: &str) -> Option<String> {
+        m.get(key).and_then(|v| v.get_string())
+    }
+
+    fn split_strings(m: &asr::settings::Map) -> Vec<Option<String>> {
+        m.get("splits")
+            .and_then(|v| v.get_list())
+            .unwrap()
+            .iter()
+            .map(|v| v.get_string())
+            .collect()
+    }
+
+    #[test]
+    fn settings_migrations_run_over_map() {
+        let m = asr::settings::Map::new();
+        let splits = asr::settings::List::new();
+        splits.push("StartNewGame");
+        splits.push(true);
+        splits.push("LegacyEnd");
+        m.insert("splits", splits);
+        m.insert("splits_2_item", "LegacyEnd");
+        assert!(asr_settings_normalize(&m).is_some());
+        assert_eq!(asr_settings_version(&m), SETTINGS_VERSION);
+        assert_eq!(
+            split_strings(&m),
+            [
+                Some("StartNewGame".to_string()),
+                None,
+                Some("EndingSplit".to_string())
+            ]
+        );
+        assert_eq!(
+            get_string(&m, "splits_2_item").as_deref(),
+            Some("EndingSplit")
+        );
+
+        // nothing left to migrate
+        assert!(asr_settings_normalize(&m).is_none());
+
+        // an alias hand-edited back in after the migration
+        let splits = asr::settings::List::new();
+        splits.push("LegacyEnd");
+        splits.push("EndingSplit");
+        m.insert("splits", splits);
+        m.insert("splits_0_item", "LegacyEnd");
+        assert!(asr_settings_normalize(&m).is_some());
+        assert_eq!(asr_settings_version(&m), SETTINGS_VERSION);
+        assert_eq!(
+            split_strings(&m),
+            [
+                Some("EndingSplit".to_string()),
+                Some("EndingSplit".to_string())
+            ]
+        );
+        assert_eq!(
+            get_string(&m, "splits_0_item").as_deref(),
+            Some("EndingSplit")
+        );
+    }
+
+    #[test]
+    fn split_aliases_migrate() {
+        assert_eq!(split_key_normalize("LegacyEnd"), "EndingSplit");
+        assert_eq!(split_key_normalize("EndingSplit"), "EndingSplit");
+        assert_eq!(split_key_normalize("NotASplit"), "NotASplit");
+    }
+}
@@ -0,0 +1,327 @@
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use asr::settings::gui::Gui;
+use ugly_widget::{
+    radio_button::{options_str, RadioButtonOptions},
+    store::StoreWidget,
+};
+
+use crate::deferred::DeferredSplit;
+use crate::split_arming::SplitArming;
+use crate::split_entry::{SplitEntry, SplitStores};
+use crate::timer::{SplitterAction, Timer};
+
+/// Which alternative of a branch a split belongs to.
+///
+/// Consecutive splits with the same branch make up one alternative,
+/// and consecutive alternatives with increasing branches make up one branch,
+/// such as A, A, B, B for a branch with two alternatives of two splits each.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum Branch {
+    /// Not in a branch
+    #[default]
+    None,
+    /// Branch A
+    A,
+    /// Branch B
+    B,
+    /// Branch C
+    C,
+    /// Branch D
+    D,
+}
+
+impl StoreWidget for Branch {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+/// The first split of an alternative other than the current split's,
+/// evaluated alongside the current split while the timer is on the first split of its branch.
+pub struct Alternative {
+    /// The index of the alternative's first split
+    pub start: usize,
+    /// Change tracking of its own, since the current split uses the shared stores
+    pub stores: SplitStores,
+    pub arming: SplitArming,
+    pub deferred: DeferredSplit,
+}
+
+impl Alternative {
+    fn new(start: usize) -> Alternative {
+        Alternative {
+            start,
+            stores: SplitStores::new(),
+            arming: SplitArming::new(),
+            deferred: DeferredSplit::new(),
+        }
+    }
+}
+
+/// Alternative routes through the splits list, laid out one after another.
+///
+/// Whichever alternative's first split happens first is taken,
+/// and the splits of the other alternatives are skipped,
+/// so that LiveSplit keeps one segment for every split in the list.
+pub struct Branches {
+    /// The index ranges of each branch's alternatives
+    groups: Vec<Vec<Range<usize>>>,
+    /// Which alternative was taken in each branch, in this attempt
+    chosen: Vec<Option<usize>>,
+    /// The index the timer was on in the last update
+    last_i: usize,
+    /// The other alternatives of the branch the timer is on the first split of, if any
+    racing: Vec<Alternative>,
+}
+
+impl Branches {
+    /// Finds the branches in the splits list,
+    /// leaving out ones that can't be taken without changing the number of splits in the run.
+    pub fn new(splits: &[SplitEntry]) -> Branches {
+        let mut groups: Vec<Vec<Range<usize>>> = Vec::new();
+        let mut last = Branch::None;
+        for (i, e) in splits.iter().enumerate() {
+            match e.branch {
+                Branch::None => (),
+                b if b == last => {
+                    if let Some(alt) = groups.last_mut().and_then(|g| g.last_mut()) {
+                        alt.end = i + 1;
+                    }
+                }
+                b if last != Branch::None && last < b => {
+                    if let Some(g) = groups.last_mut() {
+                        g.push(i..i + 1);
+                    }
+                }
+                _ => {
+                    let alt = i..i + 1;
+                    groups.push(vec![alt]);
+                }
+            }
+            last = e.branch;
+        }
+        groups.retain(|g| match check_group(g, splits.len()) {
+            Ok(()) => true,
+            Err(message) => {
+                asr::print_message(&format!(
+                    "Warning: ignoring the branch at splits {:?}, {}",
+                    g, message
+                ));
+                false
+            }
+        });
+        let chosen = vec![None; groups.len()];
+        Branches {
+            groups,
+            chosen,
+            last_i: 0,
+            racing: Vec::new(),
+        }
+    }
+
+    /// Forgets which alternative was taken in branches the timer is back before,
+    /// and starts evaluating the other alternatives when the timer reaches the start of a branch
+    pub fn update(&mut self, timer: &Timer) {
+        self.update_at(timer.i());
+    }
+
+    fn update_at(&mut self, i: usize) {
+        for (g, c) in self.groups.iter().zip(self.chosen.iter_mut()) {
+            if i <= g[0].start {
+                *c = None;
+            }
+        }
+        if i != self.last_i {
+            self.last_i = i;
+            self.racing = match self.groups.iter().find(|g| g[0].start == i) {
+                Some(g) => g[1..]
+                    .iter()
+                    .map(|alt| Alternative::new(alt.start))
+                    .collect(),
+                None => Vec::new(),
+            };
+        }
+    }
+
+    /// Whether the split at index `j` is the first split of an alternative
+    /// evaluated alongside the current split
+    pub fn is_racing(&self, j: usize) -> bool {
+        self.racing.iter().any(|alt| alt.start == j)
+    }
+
+    /// Forgets changes in the alternatives' change tracking on a transition from `old_scene`
+    pub fn on_transition(&mut self, old_scene: &str) {
+        for alt in self.racing.iter_mut() {
+            alt.stores.on_transition(old_scene);
+        }
+    }
+
+    /// Forgets addresses in the old process, when the game process is lost or attached again
+    pub fn detached(&mut self) {
+        for alt in self.racing.iter_mut() {
+            alt.stores.detached();
+        }
+    }
+
+    /// Records that the split at index `i` split,
+    /// taking its alternative if it's the first split of one.
+    pub fn record_split(&mut self, i: usize) {
+        for (g, c) in self.groups.iter().zip(self.chosen.iter_mut()) {
+            if let Some(k) = g.iter().position(|alt| alt.start == i) {
+                *c = Some(k);
+            }
+        }
+    }
+
+    /// When the split at index `i` is the first split of a branch,
+    /// evaluates the first splits of the other alternatives with `f`,
+    /// and returns the index of the first one that splits.
+    ///
+    /// Every alternative is evaluated, even after one splits,
+    /// so that none of them misses a change.
+    pub fn choose<F: FnMut(&SplitEntry, &mut Alternative) -> SplitterAction>(
+        &mut self,
+        i: usize,
+        splits: &[SplitEntry],
+        mut f: F,
+    ) -> Option<usize> {
+        if i != self.last_i {
+            return None;
+        }
+        let mut taken = None;
+        for alt in self.racing.iter_mut() {
+            let a = f(&splits[alt.start], alt);
+            if a == SplitterAction::Split && taken.is_none() {
+                taken = Some(alt.start);
+            }
+        }
+        taken
+    }
+
+    /// Whether the timer is on a split of an alternative that wasn't taken,
+    /// because an alternative before it was
+    pub fn should_skip(&self, timer: &Timer) -> bool {
+        self.skips(timer.i())
+    }
+
+    fn skips(&self, i: usize) -> bool {
+        self.groups.iter().zip(self.chosen.iter()).any(|(g, c)| {
+            let Some(c) = c else {
+                return false;
+            };
+            g.iter()
+                .position(|alt| alt.contains(&i))
+                .is_some_and(|k| *c < k)
+        })
+    }
+}
+
+fn check_group(g: &[Range<usize>], n: usize) -> Result<(), &'static str> {
+    if g.len() < 2 {
+        return Err("since it has only one alternative");
+    }
+    if g.iter().any(|alt| alt.len() != g[0].len()) {
+        return Err("since its alternatives have different numbers of splits");
+    }
+    if g[0].start == 0 || n <= g[g.len() - 1].end {
+        return Err("since it includes the start or end split");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Branch::{None as N, A, B, C};
+
+    fn entries(branches: &[Branch]) -> Vec<SplitEntry> {
+        branches
+            .iter()
+            .map(|&branch| SplitEntry {
+                branch,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_branches_of_consecutive_alternatives() {
+        let b = Branches::new(&entries(&[N, A, A, B, B, N, A, B, C, N]));
+        assert_eq!(b.groups, [vec![1..3, 3..5], vec![6..7, 7..8, 8..9]]);
+        // a lower branch starts a new group
+        let b = Branches::new(&entries(&[N, A, B, A, B, N]));
+        assert_eq!(b.groups, [vec![1..2, 2..3], vec![3..4, 4..5]]);
+    }
+
+    #[test]
+    fn leaves_out_branches_that_change_the_number_of_splits() {
+        let b = Branches::new(&entries(&[N, A, A, B, N, A, N, A, B, N, A, B]));
+        assert_eq!(b.groups, [vec![7..8, 8..9]]);
+        assert!(Branches::new(&entries(&[A, B, N])).groups.is_empty());
+    }
+
+    #[test]
+    fn checks_groups() {
+        assert_eq!(check_group(&[1..3, 3..5], 6), Ok(()));
+        assert!(check_group(&[1..3], 6).is_err());
+        assert!(check_group(&[1..3, 3..4], 6).is_err());
+        assert!(check_group(&[0..1, 1..2], 6).is_err());
+        assert!(check_group(&[1..3, 3..5], 5).is_err());
+    }
+
+    #[test]
+    fn skips_the_alternatives_after_the_one_taken() {
+        let mut b = Branches::new(&entries(&[N, A, A, B, B, C, C, N]));
+        b.update_at(1);
+        b.record_split(1);
+        b.update_at(2);
+        assert!(!b.skips(2));
+        assert!(b.skips(3));
+        assert!(b.skips(6));
+        assert!(!b.skips(7));
+        // an undo back to the start of the branch forgets the alternative taken
+        b.update_at(1);
+        assert!(!b.skips(3));
+    }
+
+    #[test]
+    fn skips_the_alternatives_after_a_later_one_taken() {
+        let mut b = Branches::new(&entries(&[N, A, A, B, B, C, C, N]));
+        b.update_at(1);
+        b.record_split(3);
+        b.update_at(4);
+        assert!(!b.skips(4));
+        assert!(b.skips(5));
+        assert!(!b.skips(7));
+    }
+
+    #[test]
+    fn evaluates_every_other_alternative_at_the_start_of_a_branch() {
+        let splits = entries(&[N, A, B, C, N]);
+        let mut b = Branches::new(&splits);
+        b.update_at(1);
+        assert!(b.is_racing(2) && b.is_racing(3) && !b.is_racing(1));
+        let mut evaluated = Vec::new();
+        let taken = b.choose(1, &splits, |_, alt| {
+            evaluated.push(alt.start);
+            SplitterAction::Split
+        });
+        assert_eq!(taken, Some(2));
+        assert_eq!(evaluated, [2, 3]);
+        assert_eq!(b.choose(2, &splits, |_, _| SplitterAction::Split), None);
+        b.update_at(2);
+        assert!(!b.is_racing(3));
+    }
+}
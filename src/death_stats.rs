@@ -0,0 +1,170 @@
+use alloc::format;
+
+use asr::timer::TimerState;
+use asr::Process;
+
+use crate::game_time::GameTime;
+use crate::hollow_knight_memory::*;
+use crate::timer::{Resettable, Timer};
+
+const VARIABLE_NAMES: [&str; 5] = [
+    "deaths",
+    "hazard respawns",
+    "dream falls",
+    "shade kills",
+    "shade geo lost",
+];
+
+/// Counts deaths, hazard respawns, dream falls, and shade kills over the run,
+/// along with the geo lost when dying again before killing the shade.
+pub struct DeathStats {
+    deaths: i64,
+    hazard_respawns: i64,
+    dream_falls: i64,
+    shade_kills: i64,
+    shade_geo_lost: i64,
+    last_dead: bool,
+    last_hazard: bool,
+    last_exiting_level: Option<SceneName>,
+    /// Whether there was a shade, and the geo it carried, while alive
+    last_shade: Option<(bool, i32)>,
+}
+
+impl Resettable for DeathStats {
+    fn ended(&mut self) {}
+    fn reset(&mut self) {
+        self.deaths = 0;
+        self.hazard_respawns = 0;
+        self.dream_falls = 0;
+        self.shade_kills = 0;
+        self.shade_geo_lost = 0;
+        self.last_dead = false;
+        self.last_hazard = false;
+        self.last_exiting_level = None;
+        self.last_shade = None;
+        self.set_variables();
+    }
+}
+
+impl DeathStats {
+    pub fn new() -> DeathStats {
+        let death_stats = DeathStats {
+            deaths: 0,
+            hazard_respawns: 0,
+            dream_falls: 0,
+            shade_kills: 0,
+            shade_geo_lost: 0,
+            last_dead: false,
+            last_hazard: false,
+            last_exiting_level: None,
+            last_shade: None,
+        };
+        death_stats.set_variables();
+        death_stats
+    }
+
+    fn set_variables(&self) {
+        asr::timer::set_variable_int("deaths", self.deaths);
+        asr::timer::set_variable_int("hazard respawns", self.hazard_respawns);
+        asr::timer::set_variable_int("dream falls", self.dream_falls);
+        asr::timer::set_variable_int("shade kills", self.shade_kills);
+        asr::timer::set_variable_int("shade geo lost", self.shade_geo_lost);
+    }
+}
+
+impl GameTime for DeathStats {
+    /// Sets death and shade variables, but does not set game time
+    fn update_variables(
+        &mut self,
+        _: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        // only count deaths if timer is running
+        if asr::timer::state() != TimerState::Running {
+            return;
+        }
+
+        let maybe_dead = game_manager_finder.hero_dead(process);
+        let maybe_hazard = game_manager_finder.hazard_death(process);
+        let maybe_scene_name = game_manager_finder.get_scene_name_buf(process);
+        let maybe_game_state = game_manager_finder.get_game_state(process);
+        let maybe_soul_limited = game_manager_finder.soul_limited(process);
+        let maybe_geo_pool = game_manager_finder.geo_pool(process);
+
+        if let Some(d) = maybe_dead {
+            if !self.last_dead && d {
+                self.deaths += 1;
+                asr::timer::set_variable_int("deaths", self.deaths);
+                // dying again replaces the old shade, and the geo it carried,
+                // except in dreams, which don't leave a shade
+                let in_dream = maybe_scene_name
+                    .and_then(|s| s.decode())
+                    .as_deref()
+                    .is_some_and(is_dream);
+                if let (Some((true, geo)), false) = (self.last_shade, in_dream) {
+                    self.shade_geo_lost += geo as i64;
+                    asr::timer::set_variable_int("shade geo lost", self.shade_geo_lost);
+                }
+                asr::print_message(&format!("deaths: {}", self.deaths));
+            }
+            self.last_dead = d;
+        }
+
+        if let Some(h) = maybe_hazard {
+            if !self.last_hazard && h {
+                self.hazard_respawns += 1;
+                asr::timer::set_variable_int("hazard respawns", self.hazard_respawns);
+            }
+            self.last_hazard = h;
+        }
+
+        if let Some(s) = maybe_scene_name {
+            if maybe_game_state == Some(GAME_STATE_ENTERING_LEVEL)
+                && self.last_exiting_level == Some(s)
+                && s.decode().as_deref().is_some_and(is_dream)
+            {
+                self.dream_falls += 1;
+                asr::timer::set_variable_int("dream falls", self.dream_falls);
+            }
+            if maybe_game_state == Some(GAME_STATE_EXITING_LEVEL) {
+                if self.last_exiting_level.is_none() {
+                    self.last_exiting_level = Some(s);
+                }
+            } else {
+                self.last_exiting_level = None;
+            }
+        }
+
+        // only look at the shade while alive and playing,
+        // so a new death or the main menu doesn't overwrite it first
+        if self.last_dead || maybe_game_state != Some(GAME_STATE_PLAYING) {
+            return;
+        }
+        if let (Some(soul_limited), Some(geo_pool)) = (maybe_soul_limited, maybe_geo_pool) {
+            if self.last_shade.is_some_and(|(l, _)| l) && !soul_limited {
+                self.shade_kills += 1;
+                asr::timer::set_variable_int("shade kills", self.shade_kills);
+                asr::print_message(&format!("shade kills: {}", self.shade_kills));
+            }
+            self.last_shade = Some((soul_limited, geo_pool));
+        }
+    }
+
+    /// Does not set game time, only variables
+    fn update_game_time(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        self.update_variables(timer, process, game_manager_finder);
+    }
+}
+
+/// Clears the death and shade variables, when they're turned off
+pub fn clear_variables() {
+    for name in VARIABLE_NAMES {
+        asr::timer::set_variable(name, "");
+    }
+}
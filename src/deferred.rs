@@ -0,0 +1,171 @@
+use asr::settings::gui::Gui;
+use ugly_widget::{
+    radio_button::{options_str, RadioButtonOptions},
+    store::StoreWidget,
+};
+
+use crate::hollow_knight_memory::*;
+use crate::split_entry::SplitEntry;
+use crate::timer::SplitterAction;
+
+/// When to split once a split's condition has split
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum Defer {
+    /// Immediately
+    #[default]
+    Immediately,
+    /// At next transition
+    ///
+    /// Splits on the next scene transition after the condition,
+    /// like Mantis Claw (Transition)
+    NextTransition,
+    /// At next menu
+    ///
+    /// Splits on the next arrival at the main menu after the condition,
+    /// like Mantis Claw (Menu)
+    NextMenu,
+}
+
+impl StoreWidget for Defer {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+/// Holds a split's condition until the transition or menu it's deferred to.
+pub struct DeferredSplit {
+    /// The index of the split being deferred
+    i: usize,
+    pending: bool,
+}
+
+impl DeferredSplit {
+    pub fn new() -> DeferredSplit {
+        DeferredSplit {
+            i: 0,
+            pending: false,
+        }
+    }
+
+    /// Passes instead of splitting when the split at index `i` is deferred,
+    /// and splits on the first transition or menu arrival once its condition has split.
+    pub fn filter(
+        &mut self,
+        i: usize,
+        e: &SplitEntry,
+        a: SplitterAction,
+        trans_now: bool,
+        ss: &mut SceneStore,
+    ) -> SplitterAction {
+        if i != self.i {
+            self.i = i;
+            self.pending = false;
+        }
+        if e.defer == Defer::Immediately {
+            return a;
+        }
+        match a {
+            SplitterAction::Split => self.pending = true,
+            SplitterAction::Pass => (),
+            a => return a,
+        }
+        let arrived = trans_now && {
+            let pair = ss.pair();
+            match e.defer {
+                Defer::Immediately => true,
+                Defer::NextTransition => pair.current != pair.old,
+                Defer::NextMenu => pair.current == MENU_TITLE,
+            }
+        };
+        if self.pending && arrived {
+            self.pending = false;
+            ss.split_this_transition = true;
+            SplitterAction::Split
+        } else {
+            SplitterAction::Pass
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn scenes(old: &str, current: &str) -> SceneStore {
+        let mut ss = SceneStore::new();
+        ss.new_curr_scene_name(Some(old.to_string()));
+        ss.new_next_scene_name(Some(current.to_string()));
+        ss
+    }
+
+    fn deferred(defer: Defer) -> SplitEntry {
+        SplitEntry {
+            defer,
+            ..Default::default()
+        }
+    }
+
+    const PASS: SplitterAction = SplitterAction::Pass;
+    const SPLIT: SplitterAction = SplitterAction::Split;
+
+    #[test]
+    fn holds_the_split_until_the_next_transition() {
+        let mut d = DeferredSplit::new();
+        let e = deferred(Defer::NextTransition);
+        let mut ss = scenes("Fungus2_14", "Fungus2_15");
+        assert_eq!(d.filter(1, &e, SPLIT, false, &mut ss), PASS);
+        // the condition going back to false doesn't drop it
+        assert_eq!(d.filter(1, &e, PASS, false, &mut ss), PASS);
+        assert!(!ss.split_this_transition);
+        assert_eq!(d.filter(1, &e, PASS, true, &mut ss), SPLIT);
+        assert!(ss.split_this_transition);
+        // once split, it doesn't split again
+        assert_eq!(d.filter(1, &e, PASS, true, &mut ss), PASS);
+    }
+
+    #[test]
+    fn holds_the_split_until_the_next_menu() {
+        let mut d = DeferredSplit::new();
+        let e = deferred(Defer::NextMenu);
+        let mut ss = scenes("Fungus2_14", "Fungus2_15");
+        assert_eq!(d.filter(1, &e, SPLIT, false, &mut ss), PASS);
+        // a transition that isn't to the menu doesn't split
+        assert_eq!(d.filter(1, &e, PASS, true, &mut ss), PASS);
+        let mut menu = scenes("Fungus2_15", MENU_TITLE);
+        assert_eq!(d.filter(1, &e, PASS, true, &mut menu), SPLIT);
+    }
+
+    #[test]
+    fn splits_immediately_without_deferring() {
+        let mut d = DeferredSplit::new();
+        let e = deferred(Defer::Immediately);
+        let mut ss = scenes("Fungus2_14", "Fungus2_15");
+        assert_eq!(d.filter(1, &e, SPLIT, false, &mut ss), SPLIT);
+    }
+
+    #[test]
+    fn reset_or_undo_drops_the_held_split() {
+        let e = deferred(Defer::NextTransition);
+        let mut ss = scenes("Fungus2_14", "Fungus2_15");
+        // a reset moves the timer back to index 0
+        let mut d = DeferredSplit::new();
+        assert_eq!(d.filter(1, &e, SPLIT, false, &mut ss), PASS);
+        assert_eq!(d.filter(0, &e, PASS, false, &mut ss), PASS);
+        assert_eq!(d.filter(1, &e, PASS, true, &mut ss), PASS);
+        // an undo moves the timer back to the split before
+        let mut d = DeferredSplit::new();
+        assert_eq!(d.filter(2, &e, SPLIT, false, &mut ss), PASS);
+        assert_eq!(d.filter(1, &e, PASS, true, &mut ss), PASS);
+        assert_eq!(d.filter(2, &e, PASS, true, &mut ss), PASS);
+        assert!(!ss.split_this_transition);
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use asr::Process;
+
+use crate::hollow_knight_memory::*;
+use crate::split_entry::SplitEntry;
+use crate::timer::SplitterAction;
+
+/// Which branches of split evaluation ran for a split, and what each returned.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitEvaluation {
+    /// The result of `continuous_splits`, or of the split's own check for Scene Data splits
+    pub continuous: SplitterAction,
+    /// The result of `transition_once_splits`,
+    /// or None if it didn't run because of an earlier branch or a split this transition
+    pub transition_once: Option<SplitterAction>,
+    /// The result of `transition_splits`,
+    /// or None if it didn't run because of an earlier branch or no transition now
+    pub transition: Option<SplitterAction>,
+}
+
+impl SplitEvaluation {
+    pub fn continuous(continuous: SplitterAction) -> SplitEvaluation {
+        SplitEvaluation {
+            continuous,
+            ..Default::default()
+        }
+    }
+
+    /// The action from the first branch that didn't pass
+    pub fn action(&self) -> SplitterAction {
+        self.continuous
+            .clone()
+            .or_else(|| self.transition_once.clone().unwrap_or_default())
+            .or_else(|| self.transition.clone().unwrap_or_default())
+    }
+}
+
+/// What each wrapper around a split's condition made of its action, in the order they apply
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WrappedAction {
+    /// After waiting for the occurrence to split on
+    pub occurrence: SplitterAction,
+    /// After arming, the same as `occurrence` for splits that aren't armed
+    pub arming: SplitterAction,
+    /// After deferral to the next transition or menu
+    pub deferral: SplitterAction,
+    /// After the delay
+    pub delay: SplitterAction,
+    /// The first split of the alternative of a branch taken instead, if any
+    pub branch: Option<usize>,
+    /// The action taken in the end
+    pub action: SplitterAction,
+}
+
+impl WrappedAction {
+    /// The action held back by occurrence, arming, and deferral, before the delay
+    pub fn held(
+        occurrence: SplitterAction,
+        arming: SplitterAction,
+        deferral: SplitterAction,
+    ) -> WrappedAction {
+        WrappedAction {
+            occurrence,
+            arming,
+            delay: deferral.clone(),
+            action: deferral.clone(),
+            deferral,
+            branch: None,
+        }
+    }
+}
+
+/// The raw values read for split evaluation:
+/// the context before evaluating the split, and the values the split read
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SplitContext {
+    pub old_scene: String,
+    pub current_scene: String,
+    pub trans_now: bool,
+    pub split_this_transition: bool,
+    pub game_state: Option<i32>,
+    pub hero_transition_state: Option<i32>,
+    pub accepting_input: Option<bool>,
+    /// The values read while evaluating the split, by the name they were read under
+    pub reads: Vec<(&'static str, String)>,
+}
+
+impl SplitContext {
+    pub fn read(
+        p: &Process,
+        g: &GameManagerFinder,
+        trans_now: bool,
+        ss: &SceneStore,
+    ) -> SplitContext {
+        let pair = ss.pair();
+        SplitContext {
+            old_scene: pair.old.into(),
+            current_scene: pair.current.into(),
+            trans_now,
+            split_this_transition: ss.split_this_transition,
+            game_state: g.get_game_state(p),
+            hero_transition_state: g.hero_transition_state(p),
+            accepting_input: g.accepting_input(p),
+            reads: Vec::new(),
+        }
+    }
+}
+
+/// Records how the current split was evaluated and what its wrappers made of it,
+/// as the `split diagnostics` variable and in the log whenever it changes.
+pub struct Diagnostics {
+    enabled: bool,
+    last_summary: Option<String>,
+    last_logged: Option<(usize, SplitContext, SplitEvaluation, WrappedAction)>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Diagnostics {
+        Diagnostics {
+            enabled: false,
+            last_summary: None,
+            last_logged: None,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if self.enabled && !enabled {
+            asr::timer::set_variable("split diagnostics", "");
+        }
+        if self.enabled != enabled {
+            self.last_summary = None;
+            self.last_logged = None;
+        }
+        self.enabled = enabled;
+    }
+
+    pub fn record(
+        &mut self,
+        i: usize,
+        entry: &SplitEntry,
+        context: SplitContext,
+        evaluation: SplitEvaluation,
+        wrapped: WrappedAction,
+    ) {
+        let branch = match wrapped.branch {
+            Some(j) => format!(", branch to split {}", j),
+            None => String::new(),
+        };
+        let summary = format!(
+            "{:?}: continuous {}, once {}, transition {}; occurrence {}, arming {}, deferral {}, delay {}{}; {}",
+            entry.split,
+            action_str(Some(&evaluation.continuous)),
+            action_str(evaluation.transition_once.as_ref()),
+            action_str(evaluation.transition.as_ref()),
+            action_str(Some(&wrapped.occurrence)),
+            action_str(Some(&wrapped.arming)),
+            action_str(Some(&wrapped.deferral)),
+            action_str(Some(&wrapped.delay)),
+            branch,
+            action_str(Some(&wrapped.action)),
+        );
+        if self.last_summary.as_ref() != Some(&summary) {
+            asr::timer::set_variable("split diagnostics", &summary);
+            self.last_summary = Some(summary);
+        }
+        let logged = (i, context, evaluation, wrapped);
+        if self.last_logged.as_ref() != Some(&logged) {
+            asr::print_message(&format!(
+                "split diagnostics: split {} {:?}, {:?}, {:?}, {:?}",
+                logged.0, entry, logged.1, logged.2, logged.3
+            ));
+            self.last_logged = Some(logged);
+        }
+    }
+}
+
+fn action_str(a: Option<&SplitterAction>) -> &'static str {
+    match a {
+        None => "-",
+        Some(SplitterAction::Pass) => "pass",
+        Some(SplitterAction::Split) => "split",
+        Some(SplitterAction::Skip) => "skip",
+        Some(SplitterAction::Reset) => "reset",
+        Some(SplitterAction::ManualSplit) => "manual",
+    }
+}
@@ -0,0 +1,181 @@
+use alloc::format;
+use alloc::string::String;
+
+use asr::timer::TimerState;
+use asr::Process;
+
+use crate::game_state_vars::TICKS_PER_GAME_STATE_VARS;
+use crate::hollow_knight_memory::*;
+use crate::settings_gui::FlowerBrokenAction;
+use crate::split_entry::SplitEntry;
+use crate::splits::Split;
+use crate::timer::{SplitterAction, Timer};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum FlowerStatus {
+    None,
+    Carried,
+    Broken,
+    /// Delivered to some number of recipients
+    Delivered(usize),
+}
+
+impl FlowerStatus {
+    fn read(p: &Process, g: &GameManagerFinder) -> Option<FlowerStatus> {
+        if g.xun_flower_broken(p)? {
+            return Some(FlowerStatus::Broken);
+        }
+        let delivered = [
+            g.elderbug_gave_flower(p),
+            g.given_emilitia_flower(p),
+            g.given_oro_flower(p),
+            g.xun_flower_given(p),
+            g.given_white_lady_flower(p),
+            g.given_godseeker_flower(p),
+        ]
+        .into_iter()
+        .filter(|given| given.is_some_and(|g| g))
+        .count();
+        if 0 < delivered {
+            Some(FlowerStatus::Delivered(delivered))
+        } else if g.has_xun_flower(p)? {
+            Some(FlowerStatus::Carried)
+        } else {
+            Some(FlowerStatus::None)
+        }
+    }
+
+    fn to_variable(self) -> String {
+        match self {
+            FlowerStatus::None => "none".into(),
+            FlowerStatus::Carried => "carried".into(),
+            FlowerStatus::Broken => "broken".into(),
+            FlowerStatus::Delivered(n) => format!("delivered to {}", n),
+        }
+    }
+}
+
+/// Whether a split needs the Delicate Flower to arrive unbroken
+fn is_flower_split(split: &Split) -> bool {
+    matches!(
+        split,
+        Split::ElderbugFlower
+            | Split::GivenEmilitiaFlower
+            | Split::GivenOroFlower
+            | Split::FlowerQuest
+            | Split::FlowerRewardGiven
+            | Split::GivenWhiteLadyFlower
+            | Split::GivenGodseekerFlower
+    )
+}
+
+/// Publishes the Delicate Flower status variable,
+/// and resets or skips ahead when the flower breaks during a run.
+pub struct FlowerQuest {
+    var: bool,
+    broken_action: FlowerBrokenAction,
+    last_status: Option<FlowerStatus>,
+    /// Whether the flower was broken, the last time it was read while playing
+    last_broken: Option<bool>,
+    /// Whether flower splits are being skipped after the flower broke
+    skipping: bool,
+    ticks_since_update: usize,
+}
+
+impl FlowerQuest {
+    pub fn new(var: bool, broken_action: FlowerBrokenAction) -> FlowerQuest {
+        FlowerQuest {
+            var,
+            broken_action,
+            last_status: None,
+            last_broken: None,
+            skipping: false,
+            ticks_since_update: TICKS_PER_GAME_STATE_VARS,
+        }
+    }
+
+    pub fn var(&self) -> bool {
+        self.var
+    }
+
+    pub fn broken_action(&self) -> FlowerBrokenAction {
+        self.broken_action
+    }
+
+    pub fn renew(&mut self, var: bool, broken_action: FlowerBrokenAction) {
+        if self.var && !var {
+            asr::timer::set_variable("flower", "");
+        }
+        self.var = var;
+        self.broken_action = broken_action;
+        self.last_status = None;
+        self.skipping = false;
+        self.ticks_since_update = TICKS_PER_GAME_STATE_VARS;
+    }
+
+    /// Updates the flower variable, and returns the action to take
+    /// on the current split when the flower breaks.
+    pub fn action(
+        &mut self,
+        p: &Process,
+        g: &GameManagerFinder,
+        timer: &Timer,
+        splits: &[SplitEntry],
+    ) -> SplitterAction {
+        if !self.var && self.broken_action == FlowerBrokenAction::None {
+            return SplitterAction::Pass;
+        }
+        let running = asr::timer::state() == TimerState::Running;
+        let mut broke = false;
+        // only read the flower while playing, so the main menu doesn't look like a new flower
+        if g.get_game_state(p) == Some(GAME_STATE_PLAYING) {
+            if let Some(broken) = g.xun_flower_broken(p) {
+                broke = broken && self.last_broken == Some(false);
+                if self.last_broken != Some(broken) {
+                    self.ticks_since_update = TICKS_PER_GAME_STATE_VARS;
+                }
+                self.last_broken = Some(broken);
+            }
+        }
+        if self.var {
+            self.update_variable(p, g);
+        }
+        if broke && running {
+            asr::print_message("Delicate Flower broken");
+            match self.broken_action {
+                FlowerBrokenAction::None => (),
+                FlowerBrokenAction::Reset => return SplitterAction::Reset,
+                FlowerBrokenAction::SkipFlowerSplits => self.skipping = true,
+            }
+        }
+        if !self.skipping {
+            return SplitterAction::Pass;
+        }
+        let i = timer.i();
+        // never skip the end split, that would end the run
+        match splits.get(i) {
+            Some(e) if running && i + 1 < timer.n() && is_flower_split(&e.split) => {
+                SplitterAction::Skip
+            }
+            _ => {
+                self.skipping = false;
+                SplitterAction::Pass
+            }
+        }
+    }
+
+    fn update_variable(&mut self, p: &Process, g: &GameManagerFinder) {
+        self.ticks_since_update += 1;
+        if self.ticks_since_update < TICKS_PER_GAME_STATE_VARS {
+            return;
+        }
+        self.ticks_since_update = 0;
+        let Some(status) = FlowerStatus::read(p, g) else {
+            return;
+        };
+        if self.last_status != Some(status) {
+            asr::timer::set_variable("flower", &status.to_variable());
+            self.last_status = Some(status);
+        }
+    }
+}
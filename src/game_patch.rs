@@ -0,0 +1,167 @@
+use alloc::format;
+
+use crate::splits::Split;
+
+const VERSION_VEC_MAJOR: usize = 0;
+const VERSION_VEC_MINOR: usize = 1;
+// const VERSION_VEC_BUILD: usize = 2;
+// const VERSION_VEC_REVISION: usize = 3;
+
+/// The patch of the game, grouped by differences that matter to splits and load-removal
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum GamePatch {
+    /// 1.2.2.1 and earlier, before Lifeblood
+    Patch1221,
+    /// 1.3.x and 1.4.x, from Lifeblood through Godmaster, such as 1.4.3.2
+    Patch1432,
+    /// 1.5.x, the current patch
+    Patch15,
+    /// A version this autosplitter doesn't know about, treated like the current patch
+    Unknown,
+}
+
+/// What to expect from the game on a given patch
+pub struct PatchBehavior {
+    /// 1.3.1.5 and above swap from using LoadSceneAdditive to a SceneTransitionRoutine triggered
+    /// by BeginSceneTransitionRoutine, which doesn't set tilemapDirty back to false when you enter dnail.
+    /// However, the early control glitch can only be performed on early patches so we can avoid this check entirely
+    pub uses_scene_transition_routine: bool,
+    /// Hive Knight was added in Lifeblood
+    pub hive_knight_exists: bool,
+    /// Splits that are known to be unreliable or impossible on this patch
+    pub unreliable_splits: &'static [Split],
+}
+
+static PATCH_1221_BEHAVIOR: PatchBehavior = PatchBehavior {
+    uses_scene_transition_routine: false,
+    hive_knight_exists: false,
+    unreliable_splits: &[
+        Split::HiveKnight,
+        Split::Pantheon1,
+        Split::Pantheon2,
+        Split::Pantheon3,
+        Split::Pantheon4,
+        Split::Pantheon5,
+    ],
+};
+
+static PATCH_1432_BEHAVIOR: PatchBehavior = PatchBehavior {
+    uses_scene_transition_routine: true,
+    hive_knight_exists: true,
+    unreliable_splits: &[],
+};
+
+/// Old Dreamer Timings are for Dreamer Quit-outs on older patches
+static PATCH_15_BEHAVIOR: PatchBehavior = PatchBehavior {
+    uses_scene_transition_routine: true,
+    hive_knight_exists: true,
+    unreliable_splits: &[
+        Split::LurienDreamer,
+        Split::MonomonDreamer,
+        Split::HegemolDreamer,
+        Split::MenuLurien,
+        Split::MenuMonomon,
+        Split::MenuHegemol,
+    ],
+};
+
+impl GamePatch {
+    pub fn from_version_vec(v: &[i32]) -> GamePatch {
+        match (v.get(VERSION_VEC_MAJOR), v.get(VERSION_VEC_MINOR)) {
+            (Some(1), Some(minor)) if *minor <= 2 => GamePatch::Patch1221,
+            (Some(1), Some(3 | 4)) => GamePatch::Patch1432,
+            (Some(1), Some(5)) => GamePatch::Patch15,
+            _ => GamePatch::Unknown,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GamePatch::Patch1221 => "1.2.2.1",
+            GamePatch::Patch1432 => "1.4.3.2",
+            GamePatch::Patch15 => "1.5",
+            GamePatch::Unknown => "unknown",
+        }
+    }
+
+    pub fn behavior(&self) -> &'static PatchBehavior {
+        match self {
+            GamePatch::Patch1221 => &PATCH_1221_BEHAVIOR,
+            GamePatch::Patch1432 => &PATCH_1432_BEHAVIOR,
+            GamePatch::Patch15 | GamePatch::Unknown => &PATCH_15_BEHAVIOR,
+        }
+    }
+
+    /// Prints a warning for each split that is known to be unreliable on this patch
+    pub fn warn_unreliable_splits<'a>(&self, splits: impl IntoIterator<Item = &'a Split>) {
+        let unreliable = self.behavior().unreliable_splits;
+        for s in splits.into_iter().filter(|s| unreliable.contains(s)) {
+            asr::print_message(&format!(
+                "Warning: split {:?} is unreliable on patch {}",
+                s,
+                self.name()
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn patches_from_version_vecs() {
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 0, 3, 1]),
+            GamePatch::Patch1221
+        );
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 2, 2, 1]),
+            GamePatch::Patch1221
+        );
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 3, 1, 5]),
+            GamePatch::Patch1432
+        );
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 4, 3, 2]),
+            GamePatch::Patch1432
+        );
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 5, 78, 11833]),
+            GamePatch::Patch15
+        );
+        assert_eq!(GamePatch::from_version_vec(&[1, 5]), GamePatch::Patch15);
+    }
+
+    #[test]
+    fn unknown_versions_are_unknown() {
+        assert_eq!(GamePatch::from_version_vec(&[]), GamePatch::Unknown);
+        assert_eq!(GamePatch::from_version_vec(&[1]), GamePatch::Unknown);
+        assert_eq!(
+            GamePatch::from_version_vec(&[1, 6, 0, 0]),
+            GamePatch::Unknown
+        );
+        assert_eq!(
+            GamePatch::from_version_vec(&[2, 0, 0, 0]),
+            GamePatch::Unknown
+        );
+        // an unparsable major version reads as 0
+        assert_eq!(
+            GamePatch::from_version_vec(&[0, 5, 0, 0]),
+            GamePatch::Unknown
+        );
+    }
+
+    #[test]
+    fn unknown_behaves_like_the_current_patch() {
+        let unknown = GamePatch::Unknown.behavior();
+        let current = GamePatch::Patch15.behavior();
+        assert!(core::ptr::eq(unknown, current));
+        assert!(!GamePatch::Patch1221.behavior().hive_knight_exists);
+        assert!(GamePatch::Patch1221
+            .behavior()
+            .unreliable_splits
+            .contains(&Split::HiveKnight));
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::vec::Vec;
+
+use asr::Process;
+
+use crate::hollow_knight_memory::GameManagerFinder;
+
+/// Number of ticks between reads of the game-state custom variables,
+/// since some of them, like completion, read many pointers
+pub const TICKS_PER_GAME_STATE_VARS: usize = 0x40;
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum GameStateVar {
+    Geo,
+    Essence,
+    Grubs,
+    Masks,
+    Charms,
+    Completion,
+}
+
+impl GameStateVar {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GameStateVar::Geo => "geo",
+            GameStateVar::Essence => "essence",
+            GameStateVar::Grubs => "grubs",
+            GameStateVar::Masks => "masks",
+            GameStateVar::Charms => "charms",
+            GameStateVar::Completion => "completion",
+        }
+    }
+
+    fn read(&self, p: &Process, g: &GameManagerFinder) -> Option<i32> {
+        match self {
+            GameStateVar::Geo => g.get_geo(p),
+            GameStateVar::Essence => g.dream_orbs(p),
+            GameStateVar::Grubs => g.grubs_collected(p),
+            GameStateVar::Masks => g.max_health_base(p),
+            GameStateVar::Charms => g.charms_owned(p),
+            GameStateVar::Completion => g.game_completion(p),
+        }
+    }
+
+    fn set_variable(&self, value: i32) {
+        match self {
+            GameStateVar::Completion => {
+                asr::timer::set_variable(self.name(), &format!("{}%", value))
+            }
+            _ => asr::timer::set_variable_int(self.name(), value),
+        }
+    }
+}
+
+/// Publishes the enabled game-state custom variables,
+/// only setting a variable when its value changes.
+pub struct GameStateVars {
+    enabled: Vec<GameStateVar>,
+    values: BTreeMap<GameStateVar, i32>,
+    ticks_since_update: usize,
+}
+
+impl GameStateVars {
+    pub fn new(enabled: Vec<GameStateVar>) -> GameStateVars {
+        GameStateVars {
+            enabled,
+            values: BTreeMap::new(),
+            ticks_since_update: TICKS_PER_GAME_STATE_VARS,
+        }
+    }
+
+    pub fn enabled(&self) -> &[GameStateVar] {
+        &self.enabled
+    }
+
+    pub fn renew(&mut self, enabled: Vec<GameStateVar>) {
+        for v in self.enabled.iter() {
+            if !enabled.contains(v) {
+                asr::timer::set_variable(v.name(), "");
+            }
+        }
+        self.enabled = enabled;
+        self.values.clear();
+        self.ticks_since_update = TICKS_PER_GAME_STATE_VARS;
+    }
+
+    pub fn update(&mut self, process: &Process, game_manager_finder: &GameManagerFinder) {
+        self.ticks_since_update += 1;
+        if self.ticks_since_update < TICKS_PER_GAME_STATE_VARS {
+            return;
+        }
+        self.ticks_since_update = 0;
+        for v in self.enabled.iter() {
+            let Some(value) = v.read(process, game_manager_finder) else {
+                continue;
+            };
+            if self.values.get(v) != Some(&value) {
+                v.set_variable(value);
+                self.values.insert(*v, value);
+            }
+        }
+    }
+}
@@ -19,6 +19,13 @@ pub trait GameTime: Resettable {
         process: &Process,
         game_manager_finder: &GameManagerFinder,
     );
+    /// Writes any state that should survive the autosplitter being reloaded mid-run
+    fn snapshot_into(&self, _snapshot: &asr::settings::Map) {}
+    /// Restores the state written by `snapshot_into`
+    fn restore_from(&mut self, _timer: &Timer, _snapshot: &asr::settings::Map) {}
+    /// Forgets anything measured between ticks,
+    /// when the game process is lost or attached again
+    fn detached(&mut self) {}
 }
 
 pub struct GameTimePlusVars {
@@ -81,4 +88,25 @@ impl GameTime for GameTimePlusVars {
             v.update_variables(timer, process, game_manager_finder);
         }
     }
+
+    fn snapshot_into(&self, snapshot: &asr::settings::Map) {
+        self.main.snapshot_into(snapshot);
+        for v in self.vars.iter() {
+            v.snapshot_into(snapshot);
+        }
+    }
+
+    fn restore_from(&mut self, timer: &Timer, snapshot: &asr::settings::Map) {
+        self.main.restore_from(timer, snapshot);
+        for v in self.vars.iter_mut() {
+            v.restore_from(timer, snapshot);
+        }
+    }
+
+    fn detached(&mut self) {
+        self.main.detached();
+        for v in self.vars.iter_mut() {
+            v.detached();
+        }
+    }
 }
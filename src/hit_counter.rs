@@ -18,15 +18,32 @@ const MINUS: &str = "−";
 /// The plus symbol to use for positive numbers.
 const PLUS: &str = "+";
 
+/// What counts as hits
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HitCounting {
+    /// Recoil, hazards, and deaths, plus dream falls
+    DreamFalls,
+    /// Recoil, hazards, and deaths
+    Damage,
+    /// Health lost, in masks including lifeblood
+    MasksLost,
+}
+
 pub struct HitCounter {
-    count_dream_falling: bool,
+    counting: HitCounting,
     hits: i64,
     /// Vector up to length n. Entries:
     /// 0: 0
     /// [1,n): number of hits in the i_th segment of the active attempt, where
     /// n-1: number of hits on the last segment
+    /// Hits on a skipped segment are moved forward into the next segment,
+    /// and hits on undone segments are moved back into the restored segment.
     segments_hits: Vec<i64>,
     /// Vector up to length n. Entries:
+    /// [0,n): whether segment i was completed by a split in the active attempt.
+    /// Only completed segments are recorded as pace in comparison_hits.
+    segments_completed: Vec<bool>,
+    /// Vector up to length n. Entries:
     /// 0: 0
     /// [1,n): cumulative number of hits up through the end of segment i, where
     /// n-1: pb hits
@@ -40,24 +57,28 @@ pub struct HitCounter {
     /// Number of autosplits including both start and end.
     /// One more than the number of segments.
     n: usize,
+    /// Whether the attempt ended since the last update,
+    /// since an auto-reset safe end goes straight back to index 0
+    ended: bool,
     last_recoil: bool,
     last_hazard: bool,
     last_dead_or_0: bool,
-    last_exiting_level: Option<String>,
+    last_exiting_level: Option<SceneName>,
+    /// Health and lifeblood masks, while playing and not at a bench
+    last_health: Option<(i32, i32)>,
 }
 
 impl Resettable for HitCounter {
     fn ended(&mut self) {
-        self.comparison_hits
-            .resize(max(self.comparison_hits.len(), self.n), self.hits);
-        if 1 <= self.n {
-            self.comparison_hits[self.n - 1] = min(self.comparison_hits[self.n - 1], self.hits);
-            asr::timer::set_variable_int("pb hits", self.comparison_hits[self.n - 1]);
-        }
+        self.ended = true;
     }
     fn reset(&mut self) {
+        self.add_pace();
         self.hits = 0;
         self.segments_hits = Vec::new();
+        self.segments_completed = Vec::new();
+        self.ended = false;
+        self.last_health = None;
         store_comparison_hits(&self.comparison_hits);
         self.i = 0;
         asr::timer::set_variable_int("hits", 0);
@@ -67,7 +88,7 @@ impl Resettable for HitCounter {
 
 #[allow(unused)]
 impl HitCounter {
-    pub fn new(n: usize, count_dream_falling: bool) -> HitCounter {
+    pub fn new(n: usize, counting: HitCounting) -> HitCounter {
         asr::timer::set_variable_int("hits", 0);
         asr::timer::set_variable_int("segment hits", 0);
         let comparison_hits = load_comparison_hits().unwrap_or_default();
@@ -75,25 +96,33 @@ impl HitCounter {
             asr::timer::set_variable_int("pb hits", *pb_hits);
         }
         HitCounter {
-            count_dream_falling,
+            counting,
             hits: 0,
             segments_hits: Vec::new(),
+            segments_completed: Vec::new(),
             comparison_hits,
             i: 0,
             n,
+            ended: false,
             last_recoil: false,
             last_hazard: false,
             last_dead_or_0: false,
             last_exiting_level: None,
+            last_health: None,
         }
     }
 
+    pub fn hits(&self) -> i64 {
+        self.hits
+    }
+
     fn add_hit(&mut self) {
-        self.hits += 1;
+        self.add_hits(1);
+    }
+
+    fn add_hits(&mut self, k: i64) {
+        self.count_hits(k);
         asr::timer::set_variable_int("hits", self.hits);
-        self.segments_hits
-            .resize(max(self.segments_hits.len(), self.i + 1), 0);
-        self.segments_hits[self.i] += 1;
         asr::timer::set_variable_int("segment hits", self.segments_hits[self.i]);
         if let Some(cmp) = self.comparison_hits.get(self.i) {
             asr::timer::set_variable("delta hits", &delta_string(self.hits - *cmp));
@@ -102,11 +131,135 @@ impl HitCounter {
         }
     }
 
+    /// Restores the hits from a run snapshot, without showing them,
+    /// and returns whether the snapshot had any
+    pub fn restore_counts_from(&mut self, timer: &Timer, snapshot: &asr::settings::Map) -> bool {
+        let Some(hits) = snapshot.get("hits").and_then(|v| v.get_i64()) else {
+            return false;
+        };
+        self.hits = hits;
+        self.segments_hits = snapshot
+            .get("segments_hits")
+            .and_then(|v| i64s_from_list(&v))
+            .unwrap_or_default();
+        self.segments_completed = Vec::new();
+        if let Some(l) = snapshot
+            .get("segments_completed")
+            .and_then(|v| v.get_list())
+        {
+            for e in l.iter() {
+                let Some(c) = e.get_bool() else {
+                    break;
+                };
+                self.segments_completed.push(c);
+            }
+        }
+        self.i = timer.i();
+        self.n = timer.n();
+        self.segments_hits
+            .resize(max(self.segments_hits.len(), self.i + 1), 0);
+        true
+    }
+
+    /// Counts hits in the current segment
+    pub fn count_hits(&mut self, k: i64) {
+        self.hits += k;
+        self.segments_hits
+            .resize(max(self.segments_hits.len(), self.i + 1), 0);
+        self.segments_hits[self.i] += k;
+    }
+
+    /// Counts health lost in masks, including lifeblood.
+    /// Heals are not counted, and neither are changes while not playing or while at a bench,
+    /// such as respawning after a death or swapping charms.
+    fn update_masks_lost(&mut self, process: &Process, game_manager_finder: &GameManagerFinder) {
+        let playing = game_manager_finder.get_game_state(process) == Some(GAME_STATE_PLAYING)
+            && game_manager_finder.at_bench(process) == Some(false);
+        let (true, Some(health), Some(blue), Some(max_health)) = (
+            playing,
+            game_manager_finder.get_health(process),
+            game_manager_finder.get_health_blue(process),
+            game_manager_finder.get_max_health(process),
+        ) else {
+            self.last_health = None;
+            return;
+        };
+        if let Some((last_health, last_blue)) = self.last_health {
+            // health above a lowered max health was taken away, not lost to damage
+            let lost_health = max(0, min(last_health, max_health) - health);
+            let lost_blue = max(0, last_blue - blue);
+            let lost = (lost_health + lost_blue) as i64;
+            if 0 < lost {
+                self.add_hits(lost);
+                asr::print_message(&format!("hit: {}, from {} masks lost", self.hits, lost));
+            }
+        }
+        self.last_health = Some((health, blue));
+    }
+
+    /// Moves forward from segment self.i to segment reached,
+    /// completing segments that were split and moving hits forward from segments that were skipped.
+    fn advance(&mut self, reached: usize, skipped: impl Fn(usize) -> bool) {
+        self.segments_hits
+            .resize(max(self.segments_hits.len(), reached + 1), 0);
+        self.segments_completed
+            .resize(max(self.segments_completed.len(), reached), false);
+        for j in self.i..reached {
+            if 1 <= j && skipped(j) {
+                self.segments_hits[j + 1] += self.segments_hits[j];
+                self.segments_hits[j] = 0;
+                self.segments_completed[j] = false;
+            } else {
+                self.segments_completed[j] = true;
+            }
+        }
+    }
+
+    /// Moves back from segment self.i to segment reached,
+    /// moving hits from the undone segments back into the restored one.
+    fn undo(&mut self, reached: usize) {
+        self.segments_hits
+            .resize(max(self.segments_hits.len(), self.i + 1), 0);
+        self.segments_completed
+            .resize(max(self.segments_completed.len(), self.i + 1), false);
+        for k in reached + 1..=self.i {
+            self.segments_hits[reached] += self.segments_hits[k];
+            self.segments_hits[k] = 0;
+        }
+        for k in reached..=self.i {
+            self.segments_completed[k] = false;
+        }
+    }
+
+    /// Moves from segment self.i to the timer's index i, or to the end if the attempt ended,
+    /// recording pace at the end.
+    /// Returns whether the attempt ended, without moving self.i yet.
+    fn reach(&mut self, i: usize, skipped: impl Fn(usize) -> bool) -> bool {
+        let reached = if self.ended { self.n } else { i };
+        if self.i < reached {
+            self.advance(reached, skipped);
+        } else if 0 < reached && reached < self.i {
+            self.undo(reached);
+        }
+        if !self.ended {
+            return false;
+        }
+        self.ended = false;
+        self.add_pace();
+        true
+    }
+
+    /// Records the cumulative hits through each completed segment into comparison_hits
     fn add_pace(&mut self) {
-        self.comparison_hits
-            .resize(max(self.comparison_hits.len(), self.i), self.hits);
-        if 1 <= self.i {
-            self.comparison_hits[self.i - 1] = min(self.comparison_hits[self.i - 1], self.hits);
+        let mut cumulative = 0;
+        for (j, completed) in self.segments_completed.iter().enumerate() {
+            cumulative += self.segments_hits.get(j).copied().unwrap_or_default();
+            if !completed {
+                continue;
+            }
+            self.comparison_hits
+                .resize(max(self.comparison_hits.len(), j + 1), cumulative);
+            self.comparison_hits[j] = min(self.comparison_hits[j], cumulative);
         }
     }
 }
@@ -120,14 +273,21 @@ impl GameTime for HitCounter {
         game_manager_finder: &GameManagerFinder,
     ) {
         let i = timer.i();
-        if i != self.i {
+        if i != self.i || self.ended {
+            self.n = timer.n();
             if self.i == 0 {
                 self.comparison_hits = load_comparison_hits().unwrap_or_default();
-            } else if i == 0 {
+            }
+            if self.reach(i, |j| timer.skipped(j)) {
+                let last = self.n.checked_sub(1);
+                if let Some(pb_hits) = last.and_then(|k| self.comparison_hits.get(k)) {
+                    asr::timer::set_variable_int("pb hits", *pb_hits);
+                }
+            }
+            if i == 0 && self.i != 0 {
                 store_comparison_hits(&self.comparison_hits);
             }
             self.i = i;
-            self.n = timer.n();
             self.segments_hits
                 .resize(max(self.segments_hits.len(), i + 1), 0);
             asr::timer::set_variable_int("segment hits", self.segments_hits[i]);
@@ -138,7 +298,6 @@ impl GameTime for HitCounter {
                 asr::timer::set_variable("comparison hits", DASH);
                 asr::timer::set_variable("delta hits", DASH);
             }
-            self.add_pace();
         }
 
         // only count hits if timer is running
@@ -146,12 +305,17 @@ impl GameTime for HitCounter {
             return;
         }
 
+        if self.counting == HitCounting::MasksLost {
+            self.update_masks_lost(process, game_manager_finder);
+            return;
+        }
+
         // new state
         let maybe_recoil = game_manager_finder.hero_recoil(process);
         let maybe_hazard = game_manager_finder.hazard_death(process);
         let maybe_dead = game_manager_finder.hero_dead(process);
         let maybe_health = game_manager_finder.get_health(process);
-        let maybe_scene_name = game_manager_finder.get_scene_name(process);
+        let maybe_scene_name = game_manager_finder.get_scene_name_buf(process);
         let maybe_game_state = game_manager_finder.get_game_state(process);
 
         if let Some(r) = maybe_recoil {
@@ -180,11 +344,11 @@ impl GameTime for HitCounter {
             self.last_dead_or_0 = d;
         }
 
-        if self.count_dream_falling {
+        if self.counting == HitCounting::DreamFalls {
             if let Some(s) = maybe_scene_name {
                 if maybe_game_state == Some(GAME_STATE_ENTERING_LEVEL)
-                    && self.last_exiting_level.as_deref() == Some(&s)
-                    && is_dream(&s)
+                    && self.last_exiting_level == Some(s)
+                    && s.decode().as_deref().is_some_and(is_dream)
                 {
                     self.add_hit();
                     asr::print_message(&format!("hit: {}, from dream falling", self.hits));
@@ -216,10 +380,33 @@ impl GameTime for HitCounter {
         // https://github.com/AlexKnauth/hollowknight-autosplit-wasm/issues/83
         asr::timer::set_game_time(Duration::seconds(self.hits));
     }
+
+    fn snapshot_into(&self, snapshot: &asr::settings::Map) {
+        snapshot.insert("hits", self.hits);
+        snapshot.insert("segments_hits", list_from_i64s(&self.segments_hits));
+        let completed = asr::settings::List::new();
+        for c in self.segments_completed.iter() {
+            completed.push(*c);
+        }
+        snapshot.insert("segments_completed", completed);
+    }
+
+    fn restore_from(&mut self, timer: &Timer, snapshot: &asr::settings::Map) {
+        if !self.restore_counts_from(timer, snapshot) {
+            return;
+        }
+        asr::timer::set_variable_int("hits", self.hits);
+        asr::timer::set_variable_int("segment hits", self.segments_hits[self.i]);
+        asr::print_message(&format!("restored hits: {}", self.hits));
+    }
 }
 
 fn load_comparison_hits() -> Option<Vec<i64>> {
     let v = asr::settings::Map::load().get("comparison_hits")?;
+    i64s_from_list(&v)
+}
+
+fn i64s_from_list(v: &asr::settings::Value) -> Option<Vec<i64>> {
     let l = v.get_list()?;
     let mut r = Vec::new();
     for e in l.iter() {
@@ -231,6 +418,14 @@ fn load_comparison_hits() -> Option<Vec<i64>> {
     Some(r)
 }
 
+fn list_from_i64s(is: &[i64]) -> asr::settings::List {
+    let l = asr::settings::List::new();
+    for i in is {
+        l.push(*i);
+    }
+    l
+}
+
 fn store_comparison_hits(is: &[i64]) {
     loop {
         if store_comparison_hits_if_unchanged(is) {
@@ -240,13 +435,9 @@ fn store_comparison_hits(is: &[i64]) {
 }
 
 fn store_comparison_hits_if_unchanged(is: &[i64]) -> bool {
-    let l = asr::settings::List::new();
-    for i in is {
-        l.push(*i);
-    }
     let m = asr::settings::Map::load();
     let old = m.clone();
-    m.insert("comparison_hits", l);
+    m.insert("comparison_hits", list_from_i64s(is));
     m.store_if_unchanged(&old)
 }
 
@@ -259,3 +450,100 @@ fn delta_string(i: i64) -> String {
         format!("{}", i)
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use alloc::vec;
+
+    pub(crate) fn counter(n: usize) -> HitCounter {
+        HitCounter {
+            counting: HitCounting::Damage,
+            hits: 0,
+            segments_hits: Vec::new(),
+            segments_completed: Vec::new(),
+            comparison_hits: Vec::new(),
+            i: 0,
+            n,
+            ended: false,
+            last_recoil: false,
+            last_hazard: false,
+            last_dead_or_0: false,
+            last_exiting_level: None,
+            last_health: None,
+        }
+    }
+
+    /// Moves to the timer's index `i` like `update_variables`,
+    /// where the moves from the indices in `skips` were skips
+    fn move_to(hc: &mut HitCounter, i: usize, skips: &[usize]) -> bool {
+        let ended = hc.reach(i, |j| skips.contains(&j));
+        hc.i = i;
+        ended
+    }
+
+    #[test]
+    fn skipping_forward_moves_hits_into_the_next_segment() {
+        let mut hc = counter(4);
+        move_to(&mut hc, 1, &[]);
+        hc.count_hits(2);
+        assert!(!move_to(&mut hc, 2, &[1]));
+        assert_eq!(hc.segments_hits, vec![0, 0, 2]);
+        assert_eq!(hc.segments_completed, vec![true, false]);
+        hc.count_hits(1);
+        assert_eq!(hc.segments_hits, vec![0, 0, 3]);
+        assert_eq!(hc.hits(), 3);
+    }
+
+    #[test]
+    fn undoing_back_over_a_skipped_segment() {
+        let mut hc = counter(4);
+        move_to(&mut hc, 1, &[]);
+        hc.count_hits(1);
+        move_to(&mut hc, 2, &[]);
+        hc.count_hits(2);
+        move_to(&mut hc, 3, &[2]);
+        hc.count_hits(1);
+        assert_eq!(hc.segments_hits, vec![0, 1, 0, 3]);
+        assert_eq!(hc.segments_completed, vec![true, true, false]);
+        // undo the skip
+        move_to(&mut hc, 2, &[]);
+        assert_eq!(hc.segments_hits, vec![0, 1, 3, 0]);
+        assert_eq!(hc.segments_completed, vec![true, true, false, false]);
+        // undo the split before it
+        move_to(&mut hc, 1, &[]);
+        assert_eq!(hc.segments_hits, vec![0, 4, 0, 0]);
+        assert_eq!(hc.segments_completed, vec![true, false, false, false]);
+        assert_eq!(hc.hits(), 4);
+    }
+
+    #[test]
+    fn ending_the_run_completes_the_last_segment_and_records_pace() {
+        let mut hc = counter(3);
+        move_to(&mut hc, 1, &[]);
+        hc.count_hits(1);
+        move_to(&mut hc, 2, &[]);
+        hc.count_hits(2);
+        // an auto-reset safe end goes straight back to index 0
+        hc.ended = true;
+        assert!(move_to(&mut hc, 0, &[]));
+        assert!(!hc.ended);
+        assert_eq!(hc.segments_completed, vec![true, true, true]);
+        assert_eq!(hc.comparison_hits, vec![0, 1, 3]);
+    }
+
+    #[test]
+    fn pace_is_only_added_for_completed_segments() {
+        let mut hc = counter(4);
+        hc.comparison_hits = vec![0, 3, 5, 7];
+        move_to(&mut hc, 1, &[]);
+        hc.count_hits(1);
+        move_to(&mut hc, 2, &[]);
+        move_to(&mut hc, 3, &[2]);
+        hc.count_hits(2);
+        // reset in the last segment
+        hc.add_pace();
+        // the skipped and unfinished segments keep their old pace
+        assert_eq!(hc.comparison_hits, vec![0, 1, 5, 7]);
+    }
+}
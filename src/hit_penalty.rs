@@ -0,0 +1,160 @@
+use asr::time::Duration;
+use asr::time_util::Instant;
+use asr::Process;
+
+use crate::game_time::GameTime;
+use crate::hit_counter::HitCounter;
+use crate::hollow_knight_memory::GameManagerFinder;
+use crate::load_remover::LoadRemover;
+use crate::timer::{Resettable, Timer};
+
+/// Sets game time to load-removed time plus a penalty for every hit.
+///
+/// Load-removed time is measured here instead of by pausing the timer,
+/// so that a hit during a load still adds its penalty immediately.
+pub struct HitPenalty {
+    load_remover: LoadRemover,
+    hit_counter: HitCounter,
+    /// Seconds of penalty for each hit
+    penalty_seconds: i64,
+    /// Load-removed time so far in the run
+    load_removed_time: Duration,
+    /// When the last tick was, while the timer is running
+    last_instant: Option<Instant>,
+}
+
+impl Resettable for HitPenalty {
+    fn ended(&mut self) {
+        self.load_remover.ended();
+        self.hit_counter.ended();
+    }
+    fn reset(&mut self) {
+        self.load_remover.reset();
+        self.hit_counter.reset();
+        self.load_removed_time = Duration::ZERO;
+        self.last_instant = None;
+    }
+}
+
+impl HitPenalty {
+    pub fn new(
+        load_remover: LoadRemover,
+        hit_counter: HitCounter,
+        penalty_seconds: i64,
+    ) -> HitPenalty {
+        HitPenalty {
+            load_remover,
+            hit_counter,
+            penalty_seconds,
+            load_removed_time: Duration::ZERO,
+            last_instant: None,
+        }
+    }
+
+    fn restore_time_from(&mut self, snapshot: &asr::settings::Map) {
+        if let Some(t) = snapshot.get("load_removed_time").and_then(|v| v.get_f64()) {
+            self.load_removed_time = Duration::seconds_f64(t);
+        }
+    }
+
+    /// Load-removed time plus the penalty times hits
+    fn penalized_time(&self) -> Duration {
+        self.load_removed_time + Duration::seconds(self.penalty_seconds * self.hit_counter.hits())
+    }
+}
+
+impl GameTime for HitPenalty {
+    /// Sets hits variable, but does not set game time
+    fn update_variables(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        self.hit_counter
+            .update_variables(timer, process, game_manager_finder);
+    }
+
+    /// Sets game time to load-removed time plus the penalty times hits
+    fn update_game_time(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        asr::timer::pause_game_time();
+
+        self.hit_counter
+            .update_variables(timer, process, game_manager_finder);
+
+        let Some(paused) = self
+            .load_remover
+            .is_game_time_paused(process, game_manager_finder)
+        else {
+            self.last_instant = None;
+            return;
+        };
+        let now = Instant::now();
+        if let (Some(last), false) = (self.last_instant, paused) {
+            self.load_removed_time += now - last;
+        }
+        self.last_instant = Some(now);
+
+        asr::timer::set_game_time(self.penalized_time());
+    }
+
+    fn snapshot_into(&self, snapshot: &asr::settings::Map) {
+        self.hit_counter.snapshot_into(snapshot);
+        snapshot.insert("load_removed_time", self.load_removed_time.as_seconds_f64());
+    }
+
+    fn restore_from(&mut self, timer: &Timer, snapshot: &asr::settings::Map) {
+        self.hit_counter.restore_from(timer, snapshot);
+        self.restore_time_from(snapshot);
+    }
+
+    /// Doesn't count the time that the game was closed or being attached to
+    fn detached(&mut self) {
+        self.last_instant = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hit_counter::tests::counter;
+    use crate::timer::tests::timer;
+
+    fn penalty(penalty_seconds: i64) -> HitPenalty {
+        HitPenalty::new(LoadRemover::new(), counter(5), penalty_seconds)
+    }
+
+    #[test]
+    fn adds_the_penalty_for_every_hit() {
+        let mut hp = penalty(5);
+        hp.load_removed_time = Duration::seconds(100);
+        assert_eq!(hp.penalized_time(), Duration::seconds(100));
+        hp.hit_counter.count_hits(3);
+        assert_eq!(hp.penalized_time(), Duration::seconds(115));
+        hp.penalty_seconds = 0;
+        assert_eq!(hp.penalized_time(), Duration::seconds(100));
+    }
+
+    #[test]
+    fn snapshot_restores_time_and_hits() {
+        let mut hp = penalty(2);
+        hp.load_removed_time = Duration::milliseconds(61_500);
+        hp.hit_counter.count_hits(4);
+        let snapshot = asr::settings::Map::new();
+        hp.snapshot_into(&snapshot);
+
+        let mut restored = penalty(2);
+        assert!(restored
+            .hit_counter
+            .restore_counts_from(&timer(5), &snapshot));
+        restored.restore_time_from(&snapshot);
+        assert_eq!(restored.load_removed_time, Duration::milliseconds(61_500));
+        assert_eq!(restored.hit_counter.hits(), 4);
+        assert_eq!(restored.penalized_time(), hp.penalized_time());
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
 use alloc::format;
@@ -5,16 +6,20 @@ use alloc::string::String;
 use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
-use asr::future::{next_tick, retry};
+use asr::future::next_tick;
 use asr::game_engine::unity::mono::{self, Image, Module, UnityPointer};
 use asr::game_engine::unity::scene_manager::{self, SceneManager};
-use asr::string::ArrayCString;
 use asr::watcher::Pair;
 use asr::{Address, Address16, Address32, Address64, PointerSize, Process};
-use core::cell::OnceCell;
+use bytemuck::CheckedBitPattern;
+use core::cell::{OnceCell, RefCell};
+use core::fmt::{self, Debug, Write};
 use core::iter::FusedIterator;
 use core::mem;
 
+use crate::game_patch::GamePatch;
+use crate::log_level::log;
+
 // --------------------------------------------------------
 
 static HOLLOW_KNIGHT_NAMES: [&str; 5] = [
@@ -27,11 +32,96 @@ static HOLLOW_KNIGHT_NAMES: [&str; 5] = [
 
 const SCENE_PATH_SIZE: usize = 64;
 
+/// Longest scene name that can be read without allocating, in UTF-16 code units
+const SCENE_NAME_SIZE: usize = 64;
+
+/// A scene name read into a fixed-size buffer,
+/// so that it can be compared with the last one every tick without allocating.
+#[derive(Clone, Copy)]
+pub struct SceneName {
+    len: usize,
+    buf: [u16; SCENE_NAME_SIZE],
+}
+
+impl Default for SceneName {
+    fn default() -> Self {
+        SceneName {
+            len: 0,
+            buf: [0; SCENE_NAME_SIZE],
+        }
+    }
+}
+
+impl PartialEq for SceneName {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_utf16() == other.as_utf16()
+    }
+}
+
+impl Eq for SceneName {}
+
+impl fmt::Debug for SceneName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_char('"')?;
+        for c in char::decode_utf16(self.as_utf16().iter().copied()) {
+            f.write_char(c.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        f.write_char('"')
+    }
+}
+
+impl SceneName {
+    /// None if the name is too long to fit
+    pub fn new(s: &str) -> Option<SceneName> {
+        let mut name = SceneName::default();
+        for c in s.encode_utf16() {
+            *name.buf.get_mut(name.len)? = c;
+            name.len += 1;
+        }
+        Some(name)
+    }
+
+    /// None if the name isn't UTF-8 or is too long to fit
+    pub fn from_utf8(s: &[u8]) -> Option<SceneName> {
+        SceneName::new(core::str::from_utf8(s).ok()?)
+    }
+
+    pub fn as_utf16(&self) -> &[u16] {
+        &self.buf[..self.len]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether this is the same name as `s`, without allocating
+    pub fn is(&self, s: &str) -> bool {
+        self.as_utf16().iter().copied().eq(s.encode_utf16())
+    }
+
+    /// Whether this name starts with `prefix`, without allocating
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut name = self.as_utf16().iter().copied();
+        prefix.encode_utf16().all(|c| name.next() == Some(c))
+    }
+
+    /// Whether this is a menu scene, like `is_menu`, without allocating
+    pub fn is_menu(&self) -> bool {
+        self.is_empty() || MENU_SCENES.iter().any(|m| self.is(m))
+    }
+
+    /// Allocates a String, only to be done when the name changes
+    pub fn decode(&self) -> Option<String> {
+        String::from_utf16(self.as_utf16()).ok()
+    }
+}
+
 struct StringListOffsets {
     pointer_size: PointerSize,
     string_len: u64,
     string_contents: u64,
     list_array: u64,
+    list_size: u64,
     array_len: u64,
     array_contents: u64,
 }
@@ -44,6 +134,7 @@ impl StringListOffsets {
                 string_len: 0x10,
                 string_contents: 0x14,
                 list_array: 0x10,
+                list_size: 0x18,
                 array_len: 0x18,
                 array_contents: 0x20,
             },
@@ -52,6 +143,7 @@ impl StringListOffsets {
                 string_len: 0x8,
                 string_contents: 0xc,
                 list_array: 0x8,
+                list_size: 0xc,
                 array_len: 0xc,
                 array_contents: 0x10,
             },
@@ -64,6 +156,7 @@ const PRE_MENU_INTRO: &str = "Pre_Menu_Intro";
 pub const MENU_TITLE: &str = "Menu_Title";
 pub const QUIT_TO_MENU: &str = "Quit_To_Menu";
 pub const PERMA_DEATH: &str = "PermaDeath";
+const MENU_SCENES: [&str; 3] = [MENU_TITLE, QUIT_TO_MENU, PERMA_DEATH];
 pub const INTRO_CUTSCENE: &str = "Intro_Cutscene";
 pub const OPENING_SEQUENCE: &str = "Opening_Sequence";
 pub const GG_ENTRANCE_CUTSCENE: &str = "GG_Entrance_Cutscene";
@@ -148,13 +241,6 @@ pub static GODHOME_LORE_SCENES: &[&str] = &[
     "GG_Wyrm",
 ];
 
-// --------------------------------------------------------
-
-const VERSION_VEC_MAJOR: usize = 0;
-const VERSION_VEC_MINOR: usize = 1;
-// const VERSION_VEC_BUILD: usize = 2;
-// const VERSION_VEC_REVISION: usize = 3;
-
 pub const GAME_STATE_INACTIVE: i32 = 0;
 pub const GAME_STATE_MAIN_MENU: i32 = 1;
 pub const GAME_STATE_LOADING: i32 = 2;
@@ -177,7 +263,7 @@ pub const UI_STATE_PAUSED: i32 = 7;
 
 pub const HERO_TRANSITION_STATE_WAITING_TO_ENTER_LEVEL: i32 = 2;
 
-#[derive(bytemuck::CheckedBitPattern, Clone, Copy)]
+#[derive(bytemuck::CheckedBitPattern, Clone, Copy, Debug)]
 #[repr(C)]
 pub struct Vector3 {
     pub x: f32,
@@ -349,8 +435,10 @@ impl GameManagerPointers {
 struct PlayerDataPointers {
     version: UnityPointer<4>,
     disable_pause: UnityPointer<3>,
+    play_time: UnityPointer<3>,
     health: UnityPointer<3>,
     max_health: UnityPointer<3>,
+    health_blue: UnityPointer<3>,
     mpcharge: UnityPointer<3>,
     fireball_level: UnityPointer<3>,
     quake_level: UnityPointer<3>,
@@ -381,6 +469,7 @@ struct PlayerDataPointers {
     soul_limited: UnityPointer<3>,
     /// Magic Power Reserve Max: amount of soul that can be held by soul vessels, 33 each
     mp_reserve_max: UnityPointer<3>,
+    mp_reserve: UnityPointer<3>,
     vessel_fragments: UnityPointer<3>,
     at_bench: UnityPointer<3>,
     // Dreamers
@@ -405,8 +494,8 @@ struct PlayerDataPointers {
     has_kings_brand: UnityPointer<3>,
     has_tram_pass: UnityPointer<3>,
     cornifer_at_home: UnityPointer<3>,
-    #[cfg(debug_assertions)]
     geo: UnityPointer<3>,
+    geo_pool: UnityPointer<3>,
     // Nail and Pale Ore
     nail_smith_upgrades: UnityPointer<3>,
     ore: UnityPointer<3>,
@@ -504,6 +593,7 @@ struct PlayerDataPointers {
     got_brumms_flame: UnityPointer<3>,
     // Kingsoul / VoidHeart
     charm_cost_36: UnityPointer<3>,
+    got_charm_36: UnityPointer<3>,
     got_queen_fragment: UnityPointer<3>,
     got_king_fragment: UnityPointer<3>,
     royal_charm_state: UnityPointer<3>,
@@ -587,6 +677,7 @@ struct PlayerDataPointers {
     /// Met Grey Mourner
     met_xun: UnityPointer<3>,
     has_xun_flower: UnityPointer<3>,
+    xun_flower_broken: UnityPointer<3>,
     xun_reward_given: UnityPointer<3>,
     opened_city_gate: UnityPointer<3>,
     visited_ruins: UnityPointer<3>,
@@ -737,12 +828,22 @@ impl PlayerDataPointers {
                 0,
                 &["_instance", "playerData", "disablePause"],
             ),
+            play_time: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "playerData", "playTime"],
+            ),
             health: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "health"]),
             max_health: UnityPointer::new(
                 "GameManager",
                 0,
                 &["_instance", "playerData", "maxHealth"],
             ),
+            health_blue: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "playerData", "healthBlue"],
+            ),
             mpcharge: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "MPCharge"]),
             fireball_level: UnityPointer::new(
                 "GameManager",
@@ -835,6 +936,11 @@ impl PlayerDataPointers {
                 0,
                 &["_instance", "playerData", "MPReserveMax"],
             ),
+            mp_reserve: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "playerData", "MPReserve"],
+            ),
             vessel_fragments: UnityPointer::new(
                 "GameManager",
                 0,
@@ -938,8 +1044,8 @@ impl PlayerDataPointers {
                 0,
                 &["_instance", "playerData", "corniferAtHome"],
             ),
-            #[cfg(debug_assertions)]
             geo: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "geo"]),
+            geo_pool: UnityPointer::new("GameManager", 0, &["_instance", "playerData", "geoPool"]),
             // Nail and Pale Ore
             nail_smith_upgrades: UnityPointer::new(
                 "GameManager",
@@ -1369,6 +1475,11 @@ impl PlayerDataPointers {
                 0,
                 &["_instance", "playerData", "charmCost_36"],
             ),
+            got_charm_36: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "playerData", "gotCharm_36"],
+            ),
             got_queen_fragment: UnityPointer::new(
                 "GameManager",
                 0,
@@ -1757,6 +1868,11 @@ impl PlayerDataPointers {
                 0,
                 &["_instance", "playerData", "hasXunFlower"],
             ),
+            xun_flower_broken: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "playerData", "xunFlowerBroken"],
+            ),
             xun_reward_given: UnityPointer::new(
                 "GameManager",
                 0,
@@ -2427,7 +2543,7 @@ impl CompletionPointers {
     }
 }
 
-#[derive(bytemuck::CheckedBitPattern, Clone, Copy)] // bytemuck::Zeroable
+#[derive(bytemuck::CheckedBitPattern, Clone, Copy, Debug)] // bytemuck::Zeroable
 #[repr(C)]
 pub struct BossSequenceDoorCompletion {
     can_unlock: bool, // canUnlock
@@ -2443,7 +2559,9 @@ pub struct BossSequenceDoorCompletion {
 
 struct SceneDataPointers {
     persistent_bool_items: UnityPointer<3>,
+    persistent_int_items: UnityPointer<3>,
     offsets: OnceCell<SceneDataOffsets>,
+    int_offsets: OnceCell<SceneDataIntOffsets>,
 }
 
 struct SceneDataOffsets {
@@ -2452,6 +2570,20 @@ struct SceneDataOffsets {
     persistentbooldata_activated: u32,
 }
 
+struct SceneDataIntOffsets {
+    persistentintdata_id: u32,
+    persistentintdata_scenename: u32,
+    persistentintdata_value: u32,
+}
+
+/// A list of persistent items in the scene data, along with the offsets of their fields
+struct PersistentItemList<'a> {
+    pointer: &'a UnityPointer<3>,
+    scene_name: u32,
+    id: u32,
+    value: u32,
+}
+
 impl SceneDataPointers {
     fn new() -> SceneDataPointers {
         SceneDataPointers {
@@ -2460,7 +2592,13 @@ impl SceneDataPointers {
                 0,
                 &["_instance", "sceneData", "persistentBoolItems"],
             ),
+            persistent_int_items: UnityPointer::new(
+                "GameManager",
+                0,
+                &["_instance", "sceneData", "persistentIntItems"],
+            ),
             offsets: OnceCell::new(),
+            int_offsets: OnceCell::new(),
         }
     }
 
@@ -2481,6 +2619,88 @@ impl SceneDataPointers {
         };
         Some(self.offsets.get_or_init(|| o))
     }
+
+    fn int_offsets(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: &Image,
+    ) -> Option<&SceneDataIntOffsets> {
+        if let Some(o) = self.int_offsets.get() {
+            return Some(o);
+        }
+        let c = image.get_class(process, module, "PersistentIntData")?;
+        let o = SceneDataIntOffsets {
+            persistentintdata_id: c.get_field_offset(process, module, "id")?,
+            persistentintdata_scenename: c.get_field_offset(process, module, "sceneName")?,
+            persistentintdata_value: c.get_field_offset(process, module, "value")?,
+        };
+        Some(self.int_offsets.get_or_init(|| o))
+    }
+
+    fn bool_item_list(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: &Image,
+    ) -> Option<PersistentItemList<'_>> {
+        let o = self.offsets(process, module, image)?;
+        Some(PersistentItemList {
+            pointer: &self.persistent_bool_items,
+            scene_name: o.persistentbooldata_scenename,
+            id: o.persistentbooldata_id,
+            value: o.persistentbooldata_activated,
+        })
+    }
+
+    fn int_item_list(
+        &self,
+        process: &Process,
+        module: &Module,
+        image: &Image,
+    ) -> Option<PersistentItemList<'_>> {
+        let o = self.int_offsets(process, module, image)?;
+        Some(PersistentItemList {
+            pointer: &self.persistent_int_items,
+            scene_name: o.persistentintdata_scenename,
+            id: o.persistentintdata_id,
+            value: o.persistentintdata_value,
+        })
+    }
+}
+
+// --------------------------------------------------------
+
+/// Number of attempts at each load-removal pointer in `init_load_removal_pointers`
+/// before giving up on the current module and image
+const INIT_POINTER_ATTEMPTS: usize = 0x400;
+
+const PATH_GAME_STATE: &str = "GameManager._instance.gameState";
+const PATH_UI_STATE: &str = "GameManager._instance.<ui>k__BackingField.uiState";
+const PATH_SCENE_NAME: &str = "GameManager._instance.sceneName";
+const PATH_NEXT_SCENE_NAME: &str = "GameManager._instance.nextSceneName";
+const PATH_CAMERA_TELEPORTING: &str =
+    "GameManager._instance.<cameraCtrl>k__BackingField.teleporting";
+const PATH_ACCEPTING_INPUT: &str =
+    "GameManager._instance.<inputHandler>k__BackingField.acceptingInput";
+const PATH_TILE_MAP_DIRTY: &str = "GameManager._instance.tilemapDirty";
+const PATH_PLAYER_DATA_VERSION: &str = "GameManager._instance.playerData.version";
+/// Reads the same pointer as `PATH_PLAYER_DATA_VERSION`,
+/// but fails separately when the version string doesn't parse as a game patch
+const PATH_GAME_PATCH: &str = "GameManager._instance.playerData.version as a game patch";
+const PATH_HEALTH: &str = "GameManager._instance.playerData.health";
+
+async fn retry_limited<T>(
+    path: &'static str,
+    mut f: impl FnMut() -> Option<T>,
+) -> Result<T, &'static str> {
+    for _ in 0..INIT_POINTER_ATTEMPTS {
+        if let Some(t) = f() {
+            return Ok(t);
+        }
+        next_tick().await;
+    }
+    Err(path)
 }
 
 // --------------------------------------------------------
@@ -2496,6 +2716,9 @@ pub struct GameManagerFinder {
     scene_data_pointers: Box<SceneDataPointers>,
     ui_state_offset: OnceCell<u32>,
     modded: OnceCell<bool>,
+    game_patch: OnceCell<GamePatch>,
+    /// The values read since split diagnostics started recording, if they are
+    recorded_reads: RefCell<Option<Vec<(&'static str, String)>>>,
 }
 
 impl GameManagerFinder {
@@ -2510,6 +2733,8 @@ impl GameManagerFinder {
             scene_data_pointers: Box::new(SceneDataPointers::new()),
             ui_state_offset: OnceCell::new(),
             modded: OnceCell::new(),
+            game_patch: OnceCell::new(),
+            recorded_reads: RefCell::new(None),
         }
     }
 
@@ -2545,6 +2770,36 @@ impl GameManagerFinder {
         }
     }
 
+    /// Starts recording the values read, for split diagnostics
+    pub fn start_recording_reads(&self) {
+        *self.recorded_reads.borrow_mut() = Some(Vec::new());
+    }
+
+    /// Stops recording and returns the values read since `start_recording_reads`
+    pub fn take_recorded_reads(&self) -> Vec<(&'static str, String)> {
+        self.recorded_reads.take().unwrap_or_default()
+    }
+
+    /// Records a value read under `name` while recording,
+    /// only formatting it then
+    pub fn record_read<V: Debug>(&self, name: &'static str, value: V) {
+        if let Some(reads) = self.recorded_reads.borrow_mut().as_mut() {
+            reads.push((name, format!("{:?}", value)));
+        }
+    }
+
+    /// Reads a value through one of the pointers, recording it under `name` while recording
+    fn read_pointer<T: CheckedBitPattern + Debug, const PN: usize>(
+        &self,
+        process: &Process,
+        name: &'static str,
+        pointer: &UnityPointer<PN>,
+    ) -> Option<T> {
+        let value = pointer.deref(process, &self.module, &self.image).ok();
+        self.record_read(name, value);
+        value
+    }
+
     fn deref_pointer<const PN: usize>(
         &self,
         process: &Process,
@@ -2563,26 +2818,32 @@ impl GameManagerFinder {
         }
     }
 
-    pub async fn init_load_removal_pointers(&self, process: &Process) {
-        retry(|| self.get_game_state(process)).await;
+    /// Initializes the pointers for load-removal, retrying each one a limited number of times.
+    /// Returns the path of the first pointer that failed, if any.
+    pub async fn init_load_removal_pointers(&self, process: &Process) -> Result<(), &'static str> {
+        retry_limited(PATH_GAME_STATE, || self.get_game_state(process)).await?;
         next_tick().await;
-        retry(|| self.get_ui_state(process)).await;
+        retry_limited(PATH_UI_STATE, || self.get_ui_state(process)).await?;
         next_tick().await;
-        retry(|| self.get_scene_name(process)).await;
+        retry_limited(PATH_SCENE_NAME, || self.get_scene_name(process)).await?;
         next_tick().await;
-        retry(|| self.get_next_scene_name(process)).await;
+        retry_limited(PATH_NEXT_SCENE_NAME, || self.get_next_scene_name(process)).await?;
         next_tick().await;
-        retry(|| self.camera_teleporting(process)).await;
+        retry_limited(PATH_CAMERA_TELEPORTING, || self.camera_teleporting(process)).await?;
         next_tick().await;
-        retry(|| self.accepting_input(process)).await;
+        retry_limited(PATH_ACCEPTING_INPUT, || self.accepting_input(process)).await?;
         next_tick().await;
-        retry(|| self.tile_map_dirty(process)).await;
+        retry_limited(PATH_TILE_MAP_DIRTY, || self.tile_map_dirty(process)).await?;
         next_tick().await;
-        retry(|| self.deref_pointer(process, &self.player_data_pointers.version)).await;
+        retry_limited(PATH_PLAYER_DATA_VERSION, || {
+            self.deref_pointer(process, &self.player_data_pointers.version)
+                .ok()
+        })
+        .await?;
         next_tick().await;
-        retry(|| self.uses_scene_transition_routine(process)).await;
+        retry_limited(PATH_GAME_PATCH, || self.get_game_patch(process)).await?;
         next_tick().await;
-        retry(|| self.get_health(process)).await;
+        retry_limited(PATH_HEALTH, || self.get_health(process)).await?;
         next_tick().await;
         self.hazard_respawning(process);
         next_tick().await;
@@ -2593,6 +2854,29 @@ impl GameManagerFinder {
         self.hazard_death(process);
         next_tick().await;
         self.hero_dead(process);
+        Ok(())
+    }
+
+    /// The paths of the pointers that should always resolve while attached,
+    /// but currently fail to.
+    pub fn failing_pointer_paths(&self, process: &Process) -> Vec<&'static str> {
+        let mut failing = Vec::new();
+        if self.get_game_state(process).is_none() {
+            failing.push(PATH_GAME_STATE);
+        }
+        if self
+            .deref_pointer(process, &self.pointers.scene_name)
+            .is_err()
+        {
+            failing.push(PATH_SCENE_NAME);
+        }
+        if self
+            .deref_pointer(process, &self.player_data_pointers.version)
+            .is_err()
+        {
+            failing.push(PATH_PLAYER_DATA_VERSION);
+        }
+        failing
     }
 
     pub fn get_scene_name(&self, process: &Process) -> Option<String> {
@@ -2602,6 +2886,22 @@ impl GameManagerFinder {
         read_string_object(process, &self.string_list_offests, s)
     }
 
+    /// Like `get_scene_name`, but into a fixed-size buffer without allocating
+    pub fn get_scene_name_buf(&self, process: &Process) -> Option<SceneName> {
+        let s = self
+            .deref_pointer(process, &self.pointers.scene_name)
+            .ok()?;
+        read_string_object_buf(process, &self.string_list_offests, s)
+    }
+
+    /// Like `get_next_scene_name`, but into a fixed-size buffer without allocating
+    pub fn get_next_scene_name_buf(&self, process: &Process) -> Option<SceneName> {
+        let s = self
+            .deref_pointer(process, &self.pointers.next_scene_name)
+            .ok()?;
+        read_string_object_buf(process, &self.string_list_offests, s)
+    }
+
     pub fn get_next_scene_name(&self, process: &Process) -> Option<String> {
         let s = self
             .deref_pointer(process, &self.pointers.next_scene_name)
@@ -2617,10 +2917,7 @@ impl GameManagerFinder {
     }
 
     pub fn get_game_state(&self, process: &Process) -> Option<i32> {
-        self.pointers
-            .game_state
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "get_game_state", &self.pointers.game_state)
     }
 
     fn is_game_state_non_menu(&self, process: &Process) -> bool {
@@ -2682,83 +2979,59 @@ impl GameManagerFinder {
     }
 
     pub fn camera_teleporting(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .camera_teleporting
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "camera_teleporting",
+            &self.pointers.camera_teleporting,
+        )
     }
 
     pub fn camera_target_destination(&self, process: &Process) -> Option<Vector3> {
-        self.pointers
-            .camera_target_destination
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "camera_target_destination",
+            &self.pointers.camera_target_destination,
+        )
     }
 
     pub fn hazard_respawning(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .hazard_respawning
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "hazard_respawning",
+            &self.pointers.hazard_respawning,
+        )
     }
 
     pub fn accepting_input(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .accepting_input
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "accepting_input", &self.pointers.accepting_input)
     }
 
     pub fn hero_transition_state(&self, process: &Process) -> Option<i32> {
-        self.pointers
-            .hero_transition_state
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "hero_transition_state",
+            &self.pointers.hero_transition_state,
+        )
     }
 
     pub fn focusing(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .focusing
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "focusing", &self.pointers.focusing)
     }
 
     pub fn tile_map_dirty(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .tile_map_dirty
-            .deref(process, &self.module, &self.image)
-            .ok()
-    }
-
-    pub fn uses_scene_transition_routine(&self, process: &Process) -> Option<bool> {
-        /*
-         * 1.3.1.5 and above swap from using LoadSceneAdditive to a SceneTransitionRoutine triggered
-         * by BeginSceneTransitionRoutine, which doesn't set tilemapDirty back to false when you enter dnail
-         * However, the early control glitch can only be performed on early patches so we can avoid this check entirely
-         */
-        // On current patch, return true
-        Some(*self.get_version_vec(process)?.get(VERSION_VEC_MINOR)? >= 3)
+        self.read_pointer(process, "tile_map_dirty", &self.pointers.tile_map_dirty)
     }
 
     pub fn on_ground(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .on_ground
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "on_ground", &self.pointers.on_ground)
     }
 
     pub fn hero_dead(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .hero_dead
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "hero_dead", &self.pointers.hero_dead)
     }
 
     pub fn hazard_death(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .hazard_death
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "hazard_death", &self.pointers.hazard_death)
     }
 
     fn hero_recoiling(&self, process: &Process) -> Option<bool> {
@@ -2788,10 +3061,7 @@ impl GameManagerFinder {
     }
 
     pub fn spell_quake(&self, process: &Process) -> Option<bool> {
-        self.pointers
-            .spell_quake
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "spell_quake", &self.pointers.spell_quake)
     }
 
     pub fn get_version_string(&self, process: &Process) -> Option<String> {
@@ -2813,251 +3083,310 @@ impl GameManagerFinder {
         )
     }
 
+    /// The patch of the game, detected from the version once and then saved
+    pub fn get_game_patch(&self, process: &Process) -> Option<GamePatch> {
+        if let Some(patch) = self.game_patch.get() {
+            return Some(*patch);
+        }
+        let patch = GamePatch::from_version_vec(&self.get_version_vec(process)?);
+        Some(*self.game_patch.get_or_init(|| patch))
+    }
+
     pub fn disable_pause(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .disable_pause
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "disable_pause",
+            &self.player_data_pointers.disable_pause,
+        )
+    }
+
+    /// The save file's in-game play time, in seconds
+    pub fn get_play_time(&self, process: &Process) -> Option<f32> {
+        self.read_pointer(
+            process,
+            "get_play_time",
+            &self.player_data_pointers.play_time,
+        )
     }
 
     pub fn get_health(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .health
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "get_health", &self.player_data_pointers.health)
     }
 
     pub fn get_max_health(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .max_health
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "get_max_health",
+            &self.player_data_pointers.max_health,
+        )
+    }
+
+    /// Lifeblood masks
+    pub fn get_health_blue(&self, process: &Process) -> Option<i32> {
+        self.read_pointer(
+            process,
+            "get_health_blue",
+            &self.player_data_pointers.health_blue,
+        )
     }
 
     pub fn get_mpcharge(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .mpcharge
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "get_mpcharge", &self.player_data_pointers.mpcharge)
     }
 
     pub fn get_quake_level(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .quake_level
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "get_quake_level",
+            &self.player_data_pointers.quake_level,
+        )
     }
 
     pub fn get_scream_level(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .scream_level
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "get_scream_level",
+            &self.player_data_pointers.scream_level,
+        )
     }
 
     pub fn has_shadow_dash(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_shadow_dash
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_shadow_dash",
+            &self.player_data_pointers.has_shadow_dash,
+        )
     }
 
     pub fn has_super_dash(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_super_dash
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_super_dash",
+            &self.player_data_pointers.has_super_dash,
+        )
     }
 
     /// hasCyclone: actually means Cyclone Slash, from Mato
     pub fn has_cyclone(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_cyclone
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_cyclone",
+            &self.player_data_pointers.has_cyclone,
+        )
     }
 
     /// hasDashSlash: secretly means Great Slash, from Sheo
     pub fn has_dash_slash(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_dash_slash
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_dash_slash",
+            &self.player_data_pointers.has_dash_slash,
+        )
     }
 
     /// hasUpwardSlash: secretly means Dash Slash, from Oro
     pub fn has_upward_slash(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_upward_slash
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_upward_slash",
+            &self.player_data_pointers.has_upward_slash,
+        )
     }
 
     pub fn dream_nail_upgraded(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .dream_nail_upgraded
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "dream_nail_upgraded",
+            &self.player_data_pointers.dream_nail_upgraded,
+        )
     }
 
     pub fn max_health_base(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .max_health_base
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "max_health_base",
+            &self.player_data_pointers.max_health_base,
+        )
     }
 
     pub fn heart_pieces(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .heart_pieces
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "heart_pieces",
+            &self.player_data_pointers.heart_pieces,
+        )
     }
 
     pub fn mp_reserve_max(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .mp_reserve_max
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mp_reserve_max",
+            &self.player_data_pointers.mp_reserve_max,
+        )
+    }
+
+    /// Soul in the soul vessels
+    pub fn mp_reserve(&self, process: &Process) -> Option<i32> {
+        self.read_pointer(process, "mp_reserve", &self.player_data_pointers.mp_reserve)
     }
 
     pub fn vessel_fragments(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .vessel_fragments
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "vessel_fragments",
+            &self.player_data_pointers.vessel_fragments,
+        )
     }
 
     pub fn at_bench(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .at_bench
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "at_bench", &self.player_data_pointers.at_bench)
     }
 
     // Dreamers
 
     pub fn mask_broken_lurien(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mask_broken_lurien
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mask_broken_lurien",
+            &self.player_data_pointers.mask_broken_lurien,
+        )
     }
 
     pub fn mask_broken_monomon(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mask_broken_monomon
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mask_broken_monomon",
+            &self.player_data_pointers.mask_broken_monomon,
+        )
     }
 
     pub fn mask_broken_hegemol(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mask_broken_hegemol
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mask_broken_hegemol",
+            &self.player_data_pointers.mask_broken_hegemol,
+        )
     }
 
     pub fn mr_mushroom_state(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .mr_mushroom_state
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mr_mushroom_state",
+            &self.player_data_pointers.mr_mushroom_state,
+        )
     }
 
     // Keys
 
     pub fn has_city_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_city_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_city_key",
+            &self.player_data_pointers.has_city_key,
+        )
     }
 
     pub fn has_lantern(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_lantern
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_lantern",
+            &self.player_data_pointers.has_lantern,
+        )
     }
 
     pub fn simple_keys(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .simple_keys
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "simple_keys",
+            &self.player_data_pointers.simple_keys,
+        )
     }
 
     pub fn has_sly_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_sly_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_sly_key",
+            &self.player_data_pointers.has_sly_key,
+        )
     }
 
     pub fn has_white_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_white_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_white_key",
+            &self.player_data_pointers.has_white_key,
+        )
     }
 
     pub fn has_love_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_love_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_love_key",
+            &self.player_data_pointers.has_love_key,
+        )
     }
 
     pub fn got_lurker_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_lurker_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_lurker_key",
+            &self.player_data_pointers.got_lurker_key,
+        )
     }
 
     pub fn sly_simple_key(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_simple_key
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_simple_key",
+            &self.player_data_pointers.sly_simple_key,
+        )
     }
 
     pub fn has_kings_brand(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_kings_brand
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_kings_brand",
+            &self.player_data_pointers.has_kings_brand,
+        )
     }
 
     pub fn has_tram_pass(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_tram_pass
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_tram_pass",
+            &self.player_data_pointers.has_tram_pass,
+        )
     }
 
-    #[cfg(debug_assertions)]
     pub fn get_geo(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .geo
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "get_geo", &self.player_data_pointers.geo)
+    }
+
+    /// Geo carried by the shade
+    pub fn geo_pool(&self, process: &Process) -> Option<i32> {
+        self.read_pointer(process, "geo_pool", &self.player_data_pointers.geo_pool)
+    }
+
+    /// Whether soul is limited by a shade
+    pub fn soul_limited(&self, process: &Process) -> Option<bool> {
+        self.read_pointer(
+            process,
+            "soul_limited",
+            &self.player_data_pointers.soul_limited,
+        )
     }
 
     // Nail and Pale Ore
 
     pub fn nail_smith_upgrades(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .nail_smith_upgrades
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "nail_smith_upgrades",
+            &self.player_data_pointers.nail_smith_upgrades,
+        )
     }
     pub fn ore(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .ore
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "ore", &self.player_data_pointers.ore)
     }
     pub fn ore_gross(&self, process: &Process) -> Option<i32> {
         let upgrades = self.nail_smith_upgrades(process)?;
@@ -3068,133 +3397,132 @@ impl GameManagerFinder {
     // Stags
 
     pub fn opened_crossroads(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_crossroads
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_crossroads",
+            &self.player_data_pointers.opened_crossroads,
+        )
     }
 
     pub fn opened_greenpath(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_greenpath
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_greenpath",
+            &self.player_data_pointers.opened_greenpath,
+        )
     }
 
     pub fn opened_fungal_wastes(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_fungal_wastes
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_fungal_wastes",
+            &self.player_data_pointers.opened_fungal_wastes,
+        )
     }
 
     pub fn opened_ruins1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_ruins1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_ruins1",
+            &self.player_data_pointers.opened_ruins1,
+        )
     }
 
     pub fn opened_ruins2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_ruins2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_ruins2",
+            &self.player_data_pointers.opened_ruins2,
+        )
     }
 
     pub fn opened_resting_grounds(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_resting_grounds
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_resting_grounds",
+            &self.player_data_pointers.opened_resting_grounds,
+        )
     }
 
     pub fn opened_hidden_station(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_hidden_station
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_hidden_station",
+            &self.player_data_pointers.opened_hidden_station,
+        )
     }
 
     pub fn opened_deepnest(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_deepnest
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_deepnest",
+            &self.player_data_pointers.opened_deepnest,
+        )
     }
 
     pub fn opened_royal_gardens(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_royal_gardens
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_royal_gardens",
+            &self.player_data_pointers.opened_royal_gardens,
+        )
     }
 
     pub fn opened_stag_nest(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_stag_nest
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_stag_nest",
+            &self.player_data_pointers.opened_stag_nest,
+        )
     }
 
     pub fn travelling(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .travelling
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "travelling", &self.player_data_pointers.travelling)
     }
 
     // Relics
     #[allow(unused)]
     pub fn trinket1(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .trinket1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "trinket1", &self.player_data_pointers.trinket1)
     }
     pub fn trinket2(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .trinket2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "trinket2", &self.player_data_pointers.trinket2)
     }
     #[allow(unused)]
     pub fn trinket3(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .trinket3
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "trinket3", &self.player_data_pointers.trinket3)
     }
     pub fn trinket4(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .trinket4
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "trinket4", &self.player_data_pointers.trinket4)
     }
 
     pub fn sold_trinket1(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .sold_trinket1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sold_trinket1",
+            &self.player_data_pointers.sold_trinket1,
+        )
     }
     pub fn sold_trinket2(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .sold_trinket2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sold_trinket2",
+            &self.player_data_pointers.sold_trinket2,
+        )
     }
     pub fn sold_trinket3(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .sold_trinket3
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sold_trinket3",
+            &self.player_data_pointers.sold_trinket3,
+        )
     }
     pub fn sold_trinket4(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .sold_trinket4
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sold_trinket4",
+            &self.player_data_pointers.sold_trinket4,
+        )
     }
     pub fn sold_trinkets_geo(&self, p: &Process) -> Option<i32> {
         Some(
@@ -3206,474 +3534,549 @@ impl GameManagerFinder {
     }
 
     pub fn rancid_eggs(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .rancid_eggs
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "rancid_eggs",
+            &self.player_data_pointers.rancid_eggs,
+        )
     }
 
     pub fn jinn_eggs_sold(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .jinn_eggs_sold
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "jinn_eggs_sold",
+            &self.player_data_pointers.jinn_eggs_sold,
+        )
     }
 
     // Charm Notches
     pub fn notch_shroom_ogres(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .notch_shroom_ogres
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "notch_shroom_ogres",
+            &self.player_data_pointers.notch_shroom_ogres,
+        )
     }
     pub fn salubra_notch1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .salubra_notch1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "salubra_notch1",
+            &self.player_data_pointers.salubra_notch1,
+        )
     }
     pub fn salubra_notch2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .salubra_notch2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "salubra_notch2",
+            &self.player_data_pointers.salubra_notch2,
+        )
     }
     pub fn salubra_notch3(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .salubra_notch3
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "salubra_notch3",
+            &self.player_data_pointers.salubra_notch3,
+        )
     }
     pub fn salubra_notch4(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .salubra_notch4
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "salubra_notch4",
+            &self.player_data_pointers.salubra_notch4,
+        )
     }
     pub fn notch_fog_canyon(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .notch_fog_canyon
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "notch_fog_canyon",
+            &self.player_data_pointers.notch_fog_canyon,
+        )
     }
     pub fn got_grimm_notch(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_grimm_notch
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_grimm_notch",
+            &self.player_data_pointers.got_grimm_notch,
+        )
     }
 
     pub fn can_overcharm(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .can_overcharm
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "can_overcharm",
+            &self.player_data_pointers.can_overcharm,
+        )
     }
 
     // Charms
 
     pub fn got_charm_1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_1",
+            &self.player_data_pointers.got_charm_1,
+        )
     }
 
     pub fn got_charm_2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_2",
+            &self.player_data_pointers.got_charm_2,
+        )
     }
 
     pub fn got_charm_3(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_3
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_3",
+            &self.player_data_pointers.got_charm_3,
+        )
     }
 
     pub fn got_charm_4(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_4
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_4",
+            &self.player_data_pointers.got_charm_4,
+        )
     }
 
     pub fn got_charm_5(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_5
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_5",
+            &self.player_data_pointers.got_charm_5,
+        )
     }
 
     pub fn equipped_charm_5(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .equipped_charm_5
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "equipped_charm_5",
+            &self.player_data_pointers.equipped_charm_5,
+        )
     }
 
     pub fn got_charm_6(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_6
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_6",
+            &self.player_data_pointers.got_charm_6,
+        )
     }
 
     pub fn got_charm_7(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_7
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_7",
+            &self.player_data_pointers.got_charm_7,
+        )
     }
 
     pub fn equipped_charm_7(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .equipped_charm_7
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "equipped_charm_7",
+            &self.player_data_pointers.equipped_charm_7,
+        )
     }
 
     pub fn got_charm_8(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_8
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_8",
+            &self.player_data_pointers.got_charm_8,
+        )
     }
 
     pub fn got_charm_9(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_9
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_9",
+            &self.player_data_pointers.got_charm_9,
+        )
     }
 
     pub fn got_charm_10(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_10
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_10",
+            &self.player_data_pointers.got_charm_10,
+        )
     }
 
     pub fn got_charm_11(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_11
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_11",
+            &self.player_data_pointers.got_charm_11,
+        )
     }
 
     pub fn got_charm_12(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_12
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_12",
+            &self.player_data_pointers.got_charm_12,
+        )
     }
 
     pub fn got_charm_13(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_13
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_13",
+            &self.player_data_pointers.got_charm_13,
+        )
     }
 
     pub fn got_charm_14(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_14
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_14",
+            &self.player_data_pointers.got_charm_14,
+        )
     }
 
     pub fn got_charm_15(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_15
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_15",
+            &self.player_data_pointers.got_charm_15,
+        )
     }
 
     pub fn got_charm_16(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_16
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_16",
+            &self.player_data_pointers.got_charm_16,
+        )
     }
 
     pub fn got_charm_17(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_17
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_17",
+            &self.player_data_pointers.got_charm_17,
+        )
     }
 
     pub fn equipped_charm_17(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .equipped_charm_17
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "equipped_charm_17",
+            &self.player_data_pointers.equipped_charm_17,
+        )
     }
 
     pub fn got_charm_18(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_18
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_18",
+            &self.player_data_pointers.got_charm_18,
+        )
     }
 
     pub fn got_charm_19(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_19
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_19",
+            &self.player_data_pointers.got_charm_19,
+        )
     }
 
     pub fn got_charm_20(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_20
-            .deref(process, &self.module, &self.image)
-            .ok()
-    }
+        self.read_pointer(
+            process,
+            "got_charm_20",
+            &self.player_data_pointers.got_charm_20,
+        )
+    }
 
     pub fn got_charm_21(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_21
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_21",
+            &self.player_data_pointers.got_charm_21,
+        )
     }
 
     pub fn got_charm_22(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_22
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_22",
+            &self.player_data_pointers.got_charm_22,
+        )
     }
 
     pub fn got_charm_26(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_26
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_26",
+            &self.player_data_pointers.got_charm_26,
+        )
     }
 
     pub fn got_charm_27(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_27
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_27",
+            &self.player_data_pointers.got_charm_27,
+        )
     }
 
     pub fn got_charm_28(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_28
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_28",
+            &self.player_data_pointers.got_charm_28,
+        )
     }
 
     pub fn equipped_charm_28(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .equipped_charm_28
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "equipped_charm_28",
+            &self.player_data_pointers.equipped_charm_28,
+        )
     }
 
     pub fn got_charm_29(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_29
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_29",
+            &self.player_data_pointers.got_charm_29,
+        )
     }
 
     pub fn got_charm_30(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_30
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_30",
+            &self.player_data_pointers.got_charm_30,
+        )
     }
 
     pub fn got_charm_32(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_32
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_32",
+            &self.player_data_pointers.got_charm_32,
+        )
     }
 
     pub fn got_charm_33(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_33
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_33",
+            &self.player_data_pointers.got_charm_33,
+        )
     }
 
     pub fn got_charm_34(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_34
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_34",
+            &self.player_data_pointers.got_charm_34,
+        )
     }
 
     pub fn got_charm_35(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_35
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_35",
+            &self.player_data_pointers.got_charm_35,
+        )
     }
 
     pub fn got_charm_37(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_37
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_37",
+            &self.player_data_pointers.got_charm_37,
+        )
     }
 
     pub fn got_charm_38(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_38
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_38",
+            &self.player_data_pointers.got_charm_38,
+        )
     }
 
     pub fn got_charm_39(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_39
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_39",
+            &self.player_data_pointers.got_charm_39,
+        )
     }
 
     // Fragile / Unbreakable Charms
 
     pub fn got_charm_23(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_23
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_23",
+            &self.player_data_pointers.got_charm_23,
+        )
     }
 
     pub fn got_charm_24(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_24
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_24",
+            &self.player_data_pointers.got_charm_24,
+        )
     }
 
     pub fn got_charm_25(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_25
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_25",
+            &self.player_data_pointers.got_charm_25,
+        )
     }
 
     pub fn broken_charm_23(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .broken_charm_23
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "broken_charm_23",
+            &self.player_data_pointers.broken_charm_23,
+        )
     }
 
     pub fn broken_charm_24(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .broken_charm_24
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "broken_charm_24",
+            &self.player_data_pointers.broken_charm_24,
+        )
     }
 
     pub fn broken_charm_25(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .broken_charm_25
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "broken_charm_25",
+            &self.player_data_pointers.broken_charm_25,
+        )
     }
 
     pub fn fragile_greed_unbreakable(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .fragile_greed_unbreakable
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "fragile_greed_unbreakable",
+            &self.player_data_pointers.fragile_greed_unbreakable,
+        )
     }
 
     pub fn fragile_health_unbreakable(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .fragile_health_unbreakable
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "fragile_health_unbreakable",
+            &self.player_data_pointers.fragile_health_unbreakable,
+        )
     }
 
     pub fn fragile_strength_unbreakable(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .fragile_strength_unbreakable
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "fragile_strength_unbreakable",
+            &self.player_data_pointers.fragile_strength_unbreakable,
+        )
     }
 
     // Grimmchild / Carefree Melody
 
     pub fn got_charm_40(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_charm_40
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_charm_40",
+            &self.player_data_pointers.got_charm_40,
+        )
     }
 
     pub fn equipped_charm_40(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .equipped_charm_40
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "equipped_charm_40",
+            &self.player_data_pointers.equipped_charm_40,
+        )
     }
 
     pub fn grimm_child_level(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .grimm_child_level
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "grimm_child_level",
+            &self.player_data_pointers.grimm_child_level,
+        )
     }
 
     pub fn flames_collected(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .flames_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "flames_collected",
+            &self.player_data_pointers.flames_collected,
+        )
     }
 
     pub fn got_brumms_flame(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_brumms_flame
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_brumms_flame",
+            &self.player_data_pointers.got_brumms_flame,
+        )
     }
 
     // Kingsoul / VoidHeart
 
     pub fn charm_cost_36(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .charm_cost_36
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "charm_cost_36",
+            &self.player_data_pointers.charm_cost_36,
+        )
+    }
+
+    pub fn got_charm_36(&self, process: &Process) -> Option<bool> {
+        self.read_pointer(
+            process,
+            "got_charm_36",
+            &self.player_data_pointers.got_charm_36,
+        )
     }
 
     pub fn got_queen_fragment(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_queen_fragment
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_queen_fragment",
+            &self.player_data_pointers.got_queen_fragment,
+        )
     }
     pub fn got_king_fragment(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .got_king_fragment
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "got_king_fragment",
+            &self.player_data_pointers.got_king_fragment,
+        )
     }
 
     pub fn royal_charm_state(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .royal_charm_state
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "royal_charm_state",
+            &self.player_data_pointers.royal_charm_state,
+        )
     }
 
     pub fn grubs_collected(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .grubs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "grubs_collected",
+            &self.player_data_pointers.grubs_collected,
+        )
     }
 
     pub fn scenes_grub_rescued(&self, process: &Process) -> Option<Vec<String>> {
@@ -3691,17 +4094,15 @@ impl GameManagerFinder {
     }
 
     pub fn kills_grub_mimic(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_grub_mimic
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_grub_mimic",
+            &self.player_data_pointers.kills_grub_mimic,
+        )
     }
 
     pub fn dream_orbs(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .dream_orbs
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "dream_orbs", &self.player_data_pointers.dream_orbs)
     }
 
     pub fn scenes_encountered_dream_plant_c(&self, process: &Process) -> Option<Vec<String>> {
@@ -3721,133 +4122,138 @@ impl GameManagerFinder {
         read_string_object(process, &self.string_list_offests, s)
     }
     pub fn dream_gate_x(&self, process: &Process) -> Option<f32> {
-        self.player_data_pointers
-            .dream_gate_x
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "dream_gate_x",
+            &self.player_data_pointers.dream_gate_x,
+        )
     }
     pub fn dream_gate_y(&self, process: &Process) -> Option<f32> {
-        self.player_data_pointers
-            .dream_gate_y
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "dream_gate_y",
+            &self.player_data_pointers.dream_gate_y,
+        )
     }
 
     pub fn map_dirtmouth(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_dirtmouth
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_dirtmouth",
+            &self.player_data_pointers.map_dirtmouth,
+        )
     }
     pub fn map_crossroads(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_crossroads
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_crossroads",
+            &self.player_data_pointers.map_crossroads,
+        )
     }
     pub fn map_greenpath(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_greenpath
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_greenpath",
+            &self.player_data_pointers.map_greenpath,
+        )
     }
     pub fn map_fog_canyon(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_fog_canyon
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_fog_canyon",
+            &self.player_data_pointers.map_fog_canyon,
+        )
     }
     pub fn map_royal_gardens(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_royal_gardens
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_royal_gardens",
+            &self.player_data_pointers.map_royal_gardens,
+        )
     }
     pub fn map_fungal_wastes(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_fungal_wastes
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_fungal_wastes",
+            &self.player_data_pointers.map_fungal_wastes,
+        )
     }
     pub fn map_city(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_city
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "map_city", &self.player_data_pointers.map_city)
     }
     pub fn map_waterways(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_waterways
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_waterways",
+            &self.player_data_pointers.map_waterways,
+        )
     }
     pub fn map_mines(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_mines
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "map_mines", &self.player_data_pointers.map_mines)
     }
     pub fn map_deepnest(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_deepnest
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_deepnest",
+            &self.player_data_pointers.map_deepnest,
+        )
     }
     pub fn map_cliffs(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_cliffs
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "map_cliffs", &self.player_data_pointers.map_cliffs)
     }
     pub fn map_outskirts(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_outskirts
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_outskirts",
+            &self.player_data_pointers.map_outskirts,
+        )
     }
     pub fn map_resting_grounds(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_resting_grounds
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "map_resting_grounds",
+            &self.player_data_pointers.map_resting_grounds,
+        )
     }
     pub fn map_abyss(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .map_abyss
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "map_abyss", &self.player_data_pointers.map_abyss)
     }
 
     pub fn visited_dirtmouth(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_dirtmouth
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_dirtmouth",
+            &self.player_data_pointers.visited_dirtmouth,
+        )
     }
 
     pub fn sly_shell_frag1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_shell_frag1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_shell_frag1",
+            &self.player_data_pointers.sly_shell_frag1,
+        )
     }
     pub fn sly_shell_frag4(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_shell_frag4
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_shell_frag4",
+            &self.player_data_pointers.sly_shell_frag4,
+        )
     }
     pub fn sly_vessel_frag1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_vessel_frag1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_vessel_frag1",
+            &self.player_data_pointers.sly_vessel_frag1,
+        )
     }
     pub fn sly_vessel_frag2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_vessel_frag2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_vessel_frag2",
+            &self.player_data_pointers.sly_vessel_frag2,
+        )
     }
     pub fn sly_shop_finished(&self, p: &Process) -> Option<bool> {
         Some(
@@ -3862,1030 +4268,1415 @@ impl GameManagerFinder {
     }
 
     pub fn elderbug_gave_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .elderbug_gave_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "elderbug_gave_flower",
+            &self.player_data_pointers.elderbug_gave_flower,
+        )
     }
 
     pub fn killed_grimm(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_grimm
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_grimm",
+            &self.player_data_pointers.killed_grimm,
+        )
     }
 
     pub fn killed_nightmare_grimm(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_nightmare_grimm
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_nightmare_grimm",
+            &self.player_data_pointers.killed_nightmare_grimm,
+        )
     }
 
     pub fn killed_grey_prince(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_grey_prince
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_grey_prince",
+            &self.player_data_pointers.killed_grey_prince,
+        )
     }
 
     pub fn grey_prince_orbs_collected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .grey_prince_orbs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "grey_prince_orbs_collected",
+            &self.player_data_pointers.grey_prince_orbs_collected,
+        )
     }
 
     pub fn visited_crossroads(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_crossroads
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_crossroads",
+            &self.player_data_pointers.visited_crossroads,
+        )
     }
     pub fn crossroads_infected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .crossroads_infected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "crossroads_infected",
+            &self.player_data_pointers.crossroads_infected,
+        )
     }
 
     pub fn killed_mender_bug(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mender_bug
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mender_bug",
+            &self.player_data_pointers.killed_mender_bug,
+        )
     }
 
     pub fn killed_mawlek(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mawlek
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mawlek",
+            &self.player_data_pointers.killed_mawlek,
+        )
     }
 
     pub fn sly_rescued(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .sly_rescued
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "sly_rescued",
+            &self.player_data_pointers.sly_rescued,
+        )
     }
 
     pub fn killed_false_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_false_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_false_knight",
+            &self.player_data_pointers.killed_false_knight,
+        )
     }
 
     pub fn false_knight_dream_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .false_knight_dream_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "false_knight_dream_defeated",
+            &self.player_data_pointers.false_knight_dream_defeated,
+        )
     }
 
     pub fn false_knight_orbs_collected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .false_knight_orbs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "false_knight_orbs_collected",
+            &self.player_data_pointers.false_knight_orbs_collected,
+        )
     }
 
     /// killsPrayerSlug: Kills Maggot
     pub fn kills_prayer_slug(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_prayer_slug
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_prayer_slug",
+            &self.player_data_pointers.kills_prayer_slug,
+        )
     }
 
     /// killsRoller: Kills Little Baldur
     pub fn kills_roller(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_roller
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_roller",
+            &self.player_data_pointers.kills_roller,
+        )
     }
 
     pub fn salubra_blessing(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .salubra_blessing
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "salubra_blessing",
+            &self.player_data_pointers.salubra_blessing,
+        )
     }
 
     pub fn killed_hollow_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_hollow_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_hollow_knight",
+            &self.player_data_pointers.killed_hollow_knight,
+        )
     }
 
     pub fn killed_final_boss(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_final_boss
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_final_boss",
+            &self.player_data_pointers.killed_final_boss,
+        )
     }
 
     pub fn visited_greenpath(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_greenpath
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_greenpath",
+            &self.player_data_pointers.visited_greenpath,
+        )
     }
 
     pub fn killed_moss_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_moss_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_moss_knight",
+            &self.player_data_pointers.killed_moss_knight,
+        )
     }
 
     pub fn zote_rescued_buzzer(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .zote_rescued_buzzer
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "zote_rescued_buzzer",
+            &self.player_data_pointers.zote_rescued_buzzer,
+        )
     }
 
     pub fn hornet1_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .hornet1_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "hornet1_defeated",
+            &self.player_data_pointers.hornet1_defeated,
+        )
     }
 
     /// killedLazyFlyer: Aluba
     pub fn killed_lazy_flyer(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_lazy_flyer
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_lazy_flyer",
+            &self.player_data_pointers.killed_lazy_flyer,
+        )
     }
 
     pub fn killed_hunter_mark(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_hunter_mark
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_hunter_mark",
+            &self.player_data_pointers.killed_hunter_mark,
+        )
     }
 
     pub fn killed_ghost_no_eyes(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_no_eyes
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_no_eyes",
+            &self.player_data_pointers.killed_ghost_no_eyes,
+        )
     }
     pub fn no_eyes_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .no_eyes_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "no_eyes_defeated",
+            &self.player_data_pointers.no_eyes_defeated,
+        )
     }
 
     pub fn mega_moss_charger_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mega_moss_charger_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mega_moss_charger_defeated",
+            &self.player_data_pointers.mega_moss_charger_defeated,
+        )
     }
 
     pub fn nailsmith_convo_art(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .nailsmith_convo_art
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "nailsmith_convo_art",
+            &self.player_data_pointers.nailsmith_convo_art,
+        )
     }
 
     pub fn visited_fungus(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_fungus
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_fungus",
+            &self.player_data_pointers.visited_fungus,
+        )
     }
 
     pub fn killed_ghost_hu(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_hu
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_hu",
+            &self.player_data_pointers.killed_ghost_hu,
+        )
     }
     pub fn elder_hu_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .elder_hu_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "elder_hu_defeated",
+            &self.player_data_pointers.elder_hu_defeated,
+        )
     }
 
     pub fn bretta_rescued(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .bretta_rescued
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "bretta_rescued",
+            &self.player_data_pointers.bretta_rescued,
+        )
     }
 
     pub fn defeated_mantis_lords(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .defeated_mantis_lords
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "defeated_mantis_lords",
+            &self.player_data_pointers.defeated_mantis_lords,
+        )
     }
 
     // Gorb
     pub fn killed_ghost_aladar(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_aladar
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_aladar",
+            &self.player_data_pointers.killed_ghost_aladar,
+        )
     }
     pub fn aladar_slug_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .aladar_slug_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "aladar_slug_defeated",
+            &self.player_data_pointers.aladar_slug_defeated,
+        )
     }
 
     pub fn nightmare_lantern_lit(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .nightmare_lantern_lit
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "nightmare_lantern_lit",
+            &self.player_data_pointers.nightmare_lantern_lit,
+        )
     }
     pub fn destroyed_nightmare_lantern(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .destroyed_nightmare_lantern
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "destroyed_nightmare_lantern",
+            &self.player_data_pointers.destroyed_nightmare_lantern,
+        )
     }
 
     pub fn visited_resting_grounds(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_resting_grounds
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_resting_grounds",
+            &self.player_data_pointers.visited_resting_grounds,
+        )
     }
 
     pub fn killed_ghost_xero(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_xero
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_xero",
+            &self.player_data_pointers.killed_ghost_xero,
+        )
     }
     pub fn xero_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .xero_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "xero_defeated",
+            &self.player_data_pointers.xero_defeated,
+        )
     }
 
     pub fn glade_door_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .glade_door_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "glade_door_opened",
+            &self.player_data_pointers.glade_door_opened,
+        )
     }
     pub fn moth_departed(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .moth_departed
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "moth_departed",
+            &self.player_data_pointers.moth_departed,
+        )
     }
 
     /// Met Grey Mourner
     pub fn met_xun(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .met_xun
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(process, "met_xun", &self.player_data_pointers.met_xun)
     }
 
     /// Has Delicate Flower
     pub fn has_xun_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_xun_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_xun_flower",
+            &self.player_data_pointers.has_xun_flower,
+        )
+    }
+
+    /// Delicate Flower Broken, until picking up a new one
+    pub fn xun_flower_broken(&self, process: &Process) -> Option<bool> {
+        self.read_pointer(
+            process,
+            "xun_flower_broken",
+            &self.player_data_pointers.xun_flower_broken,
+        )
     }
 
     /// Flower Reward Given
     pub fn xun_reward_given(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .xun_reward_given
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "xun_reward_given",
+            &self.player_data_pointers.xun_reward_given,
+        )
     }
 
     pub fn opened_city_gate(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_city_gate
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_city_gate",
+            &self.player_data_pointers.opened_city_gate,
+        )
     }
 
     pub fn visited_ruins(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_ruins
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_ruins",
+            &self.player_data_pointers.visited_ruins,
+        )
     }
 
     // Lemm
     pub fn met_relic_dealer_shop(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .met_relic_dealer_shop
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "met_relic_dealer_shop",
+            &self.player_data_pointers.met_relic_dealer_shop,
+        )
     }
 
     pub fn toll_bench_city(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .toll_bench_city
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "toll_bench_city",
+            &self.player_data_pointers.toll_bench_city,
+        )
     }
 
     /// Killed Soul Twister
     pub fn killed_mage(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mage
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mage",
+            &self.player_data_pointers.killed_mage,
+        )
     }
 
     /// Killed Soul Warrior
     pub fn killed_mage_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mage_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mage_knight",
+            &self.player_data_pointers.killed_mage_knight,
+        )
     }
 
     pub fn mage_lord_encountered(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mage_lord_encountered
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mage_lord_encountered",
+            &self.player_data_pointers.mage_lord_encountered,
+        )
     }
 
     pub fn mage_lord_encountered_2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mage_lord_encountered_2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mage_lord_encountered_2",
+            &self.player_data_pointers.mage_lord_encountered_2,
+        )
     }
 
     pub fn killed_mage_lord(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mage_lord
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mage_lord",
+            &self.player_data_pointers.killed_mage_lord,
+        )
     }
 
     pub fn mage_lord_dream_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mage_lord_dream_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mage_lord_dream_defeated",
+            &self.player_data_pointers.mage_lord_dream_defeated,
+        )
     }
 
     pub fn mage_lord_orbs_collected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mage_lord_orbs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mage_lord_orbs_collected",
+            &self.player_data_pointers.mage_lord_orbs_collected,
+        )
     }
 
     pub fn killed_great_shield_zombie(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_great_shield_zombie
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_great_shield_zombie",
+            &self.player_data_pointers.killed_great_shield_zombie,
+        )
     }
 
     pub fn kills_great_shield_zombie(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_great_shield_zombie
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_great_shield_zombie",
+            &self.player_data_pointers.kills_great_shield_zombie,
+        )
     }
 
     pub fn watcher_chandelier(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .watcher_chandelier
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "watcher_chandelier",
+            &self.player_data_pointers.watcher_chandelier,
+        )
     }
 
     pub fn killed_black_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_black_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_black_knight",
+            &self.player_data_pointers.killed_black_knight,
+        )
     }
 
     pub fn collector_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .collector_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "collector_defeated",
+            &self.player_data_pointers.collector_defeated,
+        )
     }
 
     pub fn nailsmith_killed(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .nailsmith_killed
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "nailsmith_killed",
+            &self.player_data_pointers.nailsmith_killed,
+        )
     }
     pub fn nailsmith_spared(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .nailsmith_spared
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "nailsmith_spared",
+            &self.player_data_pointers.nailsmith_spared,
+        )
     }
 
     pub fn visited_mines(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_mines
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_mines",
+            &self.player_data_pointers.visited_mines,
+        )
     }
 
     /// Defeated Crystal Guardian
     pub fn defeated_mega_beam_miner(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .defeated_mega_beam_miner
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "defeated_mega_beam_miner",
+            &self.player_data_pointers.defeated_mega_beam_miner,
+        )
     }
 
     /// Kills left to complete Crystal Guardian journal
     pub fn kills_mega_beam_miner(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_mega_beam_miner
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_mega_beam_miner",
+            &self.player_data_pointers.kills_mega_beam_miner,
+        )
     }
 
     /// Kills left to complete Crystal Crawler journal
     pub fn kills_laser_bug(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_laser_bug
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_laser_bug",
+            &self.player_data_pointers.kills_laser_bug,
+        )
     }
 
     /// Kills left to complete Crystal Hunter journal
     pub fn kills_crystal_flyer(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_crystal_flyer
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_crystal_flyer",
+            &self.player_data_pointers.kills_crystal_flyer,
+        )
     }
 
     /// Kills left to complete Crystallised Husk journal
     pub fn kills_beam_miner(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_beam_miner
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_beam_miner",
+            &self.player_data_pointers.kills_beam_miner,
+        )
     }
 
     /// Kills left to complete Glimback journal
     pub fn kills_crystal_crawler(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_crystal_crawler
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_crystal_crawler",
+            &self.player_data_pointers.kills_crystal_crawler,
+        )
     }
 
     /// Kills left to complete Husk Miner journal
     pub fn kills_zombie_miner(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_zombie_miner
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_zombie_miner",
+            &self.player_data_pointers.kills_zombie_miner,
+        )
     }
 
     /// Kills left to complete Shardmite journal
     pub fn kills_mines_crawler(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .kills_mines_crawler
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "kills_mines_crawler",
+            &self.player_data_pointers.kills_mines_crawler,
+        )
     }
 
     pub fn mine_lift_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .mine_lift_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mine_lift_opened",
+            &self.player_data_pointers.mine_lift_opened,
+        )
     }
 
     pub fn opened_waterways_manhole(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_waterways_manhole
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_waterways_manhole",
+            &self.player_data_pointers.opened_waterways_manhole,
+        )
     }
 
     pub fn visited_waterways(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_waterways
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_waterways",
+            &self.player_data_pointers.visited_waterways,
+        )
     }
 
     pub fn killed_dung_defender(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_dung_defender
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_dung_defender",
+            &self.player_data_pointers.killed_dung_defender,
+        )
     }
 
     pub fn killed_white_defender(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_white_defender
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_white_defender",
+            &self.player_data_pointers.killed_white_defender,
+        )
     }
 
     pub fn white_defender_orbs_collected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .white_defender_orbs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "white_defender_orbs_collected",
+            &self.player_data_pointers.white_defender_orbs_collected,
+        )
     }
 
     pub fn met_emilitia(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .met_emilitia
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "met_emilitia",
+            &self.player_data_pointers.met_emilitia,
+        )
     }
 
     pub fn given_emilitia_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .given_emilitia_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "given_emilitia_flower",
+            &self.player_data_pointers.given_emilitia_flower,
+        )
     }
 
     pub fn killed_fluke_mother(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_fluke_mother
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_fluke_mother",
+            &self.player_data_pointers.killed_fluke_mother,
+        )
     }
 
     pub fn visited_abyss(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_abyss
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_abyss",
+            &self.player_data_pointers.visited_abyss,
+        )
     }
 
     pub fn saved_cloth(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .saved_cloth
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "saved_cloth",
+            &self.player_data_pointers.saved_cloth,
+        )
     }
 
     pub fn toll_bench_abyss(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .toll_bench_abyss
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "toll_bench_abyss",
+            &self.player_data_pointers.toll_bench_abyss,
+        )
     }
 
     pub fn killed_infected_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_infected_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_infected_knight",
+            &self.player_data_pointers.killed_infected_knight,
+        )
     }
 
     pub fn infected_knight_dream_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .infected_knight_dream_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "infected_knight_dream_defeated",
+            &self.player_data_pointers.infected_knight_dream_defeated,
+        )
     }
 
     pub fn infected_knight_orbs_collected(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .infected_knight_orbs_collected
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "infected_knight_orbs_collected",
+            &self.player_data_pointers.infected_knight_orbs_collected,
+        )
     }
 
     pub fn abyss_gate_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .abyss_gate_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "abyss_gate_opened",
+            &self.player_data_pointers.abyss_gate_opened,
+        )
     }
 
     pub fn abyss_lighthouse(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .abyss_lighthouse
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "abyss_lighthouse",
+            &self.player_data_pointers.abyss_lighthouse,
+        )
     }
 
     pub fn visited_white_palace(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_white_palace
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_white_palace",
+            &self.player_data_pointers.visited_white_palace,
+        )
     }
 
     pub fn white_palace_orb_1(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .white_palace_orb_1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "white_palace_orb_1",
+            &self.player_data_pointers.white_palace_orb_1,
+        )
     }
 
     pub fn white_palace_orb_2(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .white_palace_orb_2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "white_palace_orb_2",
+            &self.player_data_pointers.white_palace_orb_2,
+        )
     }
 
     pub fn white_palace_orb_3(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .white_palace_orb_3
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "white_palace_orb_3",
+            &self.player_data_pointers.white_palace_orb_3,
+        )
     }
 
     /// New data on hunter's journal entry Seal of Binding / Path of Pain
     pub fn new_data_binding_seal(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .new_data_binding_seal
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "new_data_binding_seal",
+            &self.player_data_pointers.new_data_binding_seal,
+        )
     }
 
     pub fn white_palace_secret_room_visited(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .white_palace_secret_room_visited
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "white_palace_secret_room_visited",
+            &self.player_data_pointers.white_palace_secret_room_visited,
+        )
     }
 
     /// Visited Kingdom's Edge
     pub fn visited_outskirts(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_outskirts
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_outskirts",
+            &self.player_data_pointers.visited_outskirts,
+        )
     }
     pub fn visited_hive(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_hive
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_hive",
+            &self.player_data_pointers.visited_hive,
+        )
     }
 
     fn hive_knight_doesnt_exist(&self, process: &Process) -> Option<bool> {
-        let v = self.get_version_vec(process)?;
-        Some((*v.get(VERSION_VEC_MAJOR)? <= 1) && (*v.get(VERSION_VEC_MINOR)? <= 2))
+        Some(!self.get_game_patch(process)?.behavior().hive_knight_exists)
     }
 
     pub fn killed_hive_knight(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_hive_knight
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_hive_knight",
+            &self.player_data_pointers.killed_hive_knight,
+        )
     }
 
     pub fn killed_giant_hopper(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_giant_hopper
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_giant_hopper",
+            &self.player_data_pointers.killed_giant_hopper,
+        )
     }
 
     pub fn given_oro_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .given_oro_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "given_oro_flower",
+            &self.player_data_pointers.given_oro_flower,
+        )
     }
 
     pub fn hornet_outskirts_defeated(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .hornet_outskirts_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "hornet_outskirts_defeated",
+            &self.player_data_pointers.hornet_outskirts_defeated,
+        )
     }
 
     pub fn killed_ghost_markoth(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_markoth
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_markoth",
+            &self.player_data_pointers.killed_ghost_markoth,
+        )
     }
     pub fn markoth_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .markoth_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "markoth_defeated",
+            &self.player_data_pointers.markoth_defeated,
+        )
     }
 
     pub fn little_fool_met(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .little_fool_met
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "little_fool_met",
+            &self.player_data_pointers.little_fool_met,
+        )
     }
 
     pub fn colosseum_bronze_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_bronze_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_bronze_opened",
+            &self.player_data_pointers.colosseum_bronze_opened,
+        )
     }
 
     pub fn seen_colosseum_title(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .seen_colosseum_title
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "seen_colosseum_title",
+            &self.player_data_pointers.seen_colosseum_title,
+        )
     }
 
     pub fn killed_zote(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_zote
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_zote",
+            &self.player_data_pointers.killed_zote,
+        )
     }
 
     pub fn colosseum_bronze_completed(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_bronze_completed
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_bronze_completed",
+            &self.player_data_pointers.colosseum_bronze_completed,
+        )
     }
 
     pub fn colosseum_silver_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_silver_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_silver_opened",
+            &self.player_data_pointers.colosseum_silver_opened,
+        )
     }
 
     pub fn colosseum_silver_completed(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_silver_completed
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_silver_completed",
+            &self.player_data_pointers.colosseum_silver_completed,
+        )
     }
 
     pub fn colosseum_gold_opened(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_gold_opened
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_gold_opened",
+            &self.player_data_pointers.colosseum_gold_opened,
+        )
     }
 
     pub fn killed_lobster_lancer(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_lobster_lancer
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_lobster_lancer",
+            &self.player_data_pointers.killed_lobster_lancer,
+        )
     }
 
     pub fn colosseum_gold_completed(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .colosseum_gold_completed
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "colosseum_gold_completed",
+            &self.player_data_pointers.colosseum_gold_completed,
+        )
     }
 
     pub fn visited_fog_canyon(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_fog_canyon
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_fog_canyon",
+            &self.player_data_pointers.visited_fog_canyon,
+        )
     }
 
     // Uumuu
     pub fn encountered_mega_jelly(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .encountered_mega_jelly
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "encountered_mega_jelly",
+            &self.player_data_pointers.encountered_mega_jelly,
+        )
     }
 
     pub fn killed_mega_jellyfish(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mega_jellyfish
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mega_jellyfish",
+            &self.player_data_pointers.killed_mega_jellyfish,
+        )
     }
 
     pub fn visited_royal_gardens(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_royal_gardens
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_royal_gardens",
+            &self.player_data_pointers.visited_royal_gardens,
+        )
     }
 
     pub fn toll_bench_queens_gardens(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .toll_bench_queens_gardens
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "toll_bench_queens_gardens",
+            &self.player_data_pointers.toll_bench_queens_gardens,
+        )
     }
 
     pub fn xun_flower_given(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .xun_flower_given
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "xun_flower_given",
+            &self.player_data_pointers.xun_flower_given,
+        )
     }
 
     pub fn killed_ghost_marmu(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_marmu
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_marmu",
+            &self.player_data_pointers.killed_ghost_marmu,
+        )
     }
     pub fn mum_caterpillar_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .mum_caterpillar_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "mum_caterpillar_defeated",
+            &self.player_data_pointers.mum_caterpillar_defeated,
+        )
     }
 
     pub fn killed_traitor_lord(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_traitor_lord
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_traitor_lord",
+            &self.player_data_pointers.killed_traitor_lord,
+        )
     }
 
     pub fn given_white_lady_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .given_white_lady_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "given_white_lady_flower",
+            &self.player_data_pointers.given_white_lady_flower,
+        )
     }
 
     pub fn visited_deepnest(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_deepnest
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_deepnest",
+            &self.player_data_pointers.visited_deepnest,
+        )
     }
     pub fn visited_deepnest_spa(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_deepnest_spa
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_deepnest_spa",
+            &self.player_data_pointers.visited_deepnest_spa,
+        )
     }
 
     pub fn zote_rescued_deepnest(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .zote_rescued_deepnest
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "zote_rescued_deepnest",
+            &self.player_data_pointers.zote_rescued_deepnest,
+        )
     }
 
     pub fn opened_tram_lower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .opened_tram_lower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "opened_tram_lower",
+            &self.player_data_pointers.opened_tram_lower,
+        )
     }
 
     pub fn killed_mimic_spider(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_mimic_spider
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_mimic_spider",
+            &self.player_data_pointers.killed_mimic_spider,
+        )
     }
 
     pub fn killed_ghost_galien(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_ghost_galien
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_ghost_galien",
+            &self.player_data_pointers.killed_ghost_galien,
+        )
     }
     pub fn galien_defeated(&self, process: &Process) -> Option<i32> {
-        self.player_data_pointers
-            .galien_defeated
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "galien_defeated",
+            &self.player_data_pointers.galien_defeated,
+        )
     }
 
     pub fn spider_capture(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .spider_capture
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "spider_capture",
+            &self.player_data_pointers.spider_capture,
+        )
     }
 
     pub fn has_godfinder(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .has_godfinder
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "has_godfinder",
+            &self.player_data_pointers.has_godfinder,
+        )
     }
 
     pub fn given_godseeker_flower(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .given_godseeker_flower
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "given_godseeker_flower",
+            &self.player_data_pointers.given_godseeker_flower,
+        )
     }
 
     pub fn visited_godhome(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .visited_godhome
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "visited_godhome",
+            &self.player_data_pointers.visited_godhome,
+        )
     }
 
     pub fn zote_statue_wall_broken(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .zote_statue_wall_broken
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "zote_statue_wall_broken",
+            &self.player_data_pointers.zote_statue_wall_broken,
+        )
     }
     pub fn ordeal_achieved(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .ordeal_achieved
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "ordeal_achieved",
+            &self.player_data_pointers.ordeal_achieved,
+        )
     }
 
     pub fn killed_nail_bros(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_nail_bros
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_nail_bros",
+            &self.player_data_pointers.killed_nail_bros,
+        )
     }
 
     pub fn boss_door_state_tier1(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
-        self.completion_pointers
-            .boss_door_state_tier1
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "boss_door_state_tier1",
+            &self.completion_pointers.boss_door_state_tier1,
+        )
     }
 
     pub fn killed_paintmaster(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_paintmaster
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_paintmaster",
+            &self.player_data_pointers.killed_paintmaster,
+        )
     }
 
     pub fn boss_door_state_tier2(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
-        self.completion_pointers
-            .boss_door_state_tier2
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "boss_door_state_tier2",
+            &self.completion_pointers.boss_door_state_tier2,
+        )
     }
 
     pub fn killed_nailsage(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_nailsage
-            .deref(process, &self.module, &self.image)
-            .ok()
+        self.read_pointer(
+            process,
+            "killed_nailsage",
+            &self.player_data_pointers.killed_nailsage,
+        )
+    }
+
+    pub fn boss_door_state_tier3(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
+        self.read_pointer(
+            process,
+            "boss_door_state_tier3",
+            &self.completion_pointers.boss_door_state_tier3,
+        )
+    }
+
+    pub fn killed_hollow_knight_prime(&self, process: &Process) -> Option<bool> {
+        self.read_pointer(
+            process,
+            "killed_hollow_knight_prime",
+            &self.player_data_pointers.killed_hollow_knight_prime,
+        )
+    }
+
+    pub fn boss_door_state_tier4(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
+        self.read_pointer(
+            process,
+            "boss_door_state_tier4",
+            &self.completion_pointers.boss_door_state_tier4,
+        )
+    }
+
+    pub fn boss_door_state_tier5(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
+        self.read_pointer(
+            process,
+            "boss_door_state_tier5",
+            &self.completion_pointers.boss_door_state_tier5,
+        )
+    }
+
+    // Game Completion
+
+    /// The number of charms owned, counting Kingsoul / Void Heart and Grimmchild / Carefree Melody as one each
+    pub fn charms_owned(&self, process: &Process) -> Option<i32> {
+        let pd = &self.player_data_pointers;
+        let got_charms = [
+            &pd.got_charm_1,
+            &pd.got_charm_2,
+            &pd.got_charm_3,
+            &pd.got_charm_4,
+            &pd.got_charm_5,
+            &pd.got_charm_6,
+            &pd.got_charm_7,
+            &pd.got_charm_8,
+            &pd.got_charm_9,
+            &pd.got_charm_10,
+            &pd.got_charm_11,
+            &pd.got_charm_12,
+            &pd.got_charm_13,
+            &pd.got_charm_14,
+            &pd.got_charm_15,
+            &pd.got_charm_16,
+            &pd.got_charm_17,
+            &pd.got_charm_18,
+            &pd.got_charm_19,
+            &pd.got_charm_20,
+            &pd.got_charm_21,
+            &pd.got_charm_22,
+            &pd.got_charm_23,
+            &pd.got_charm_24,
+            &pd.got_charm_25,
+            &pd.got_charm_26,
+            &pd.got_charm_27,
+            &pd.got_charm_28,
+            &pd.got_charm_29,
+            &pd.got_charm_30,
+            &pd.got_charm_31,
+            &pd.got_charm_32,
+            &pd.got_charm_33,
+            &pd.got_charm_34,
+            &pd.got_charm_35,
+            &pd.got_charm_36,
+            &pd.got_charm_37,
+            &pd.got_charm_38,
+            &pd.got_charm_39,
+            &pd.got_charm_40,
+        ];
+        let mut n = 0;
+        for got_charm in got_charms {
+            if got_charm
+                .deref::<bool>(process, &self.module, &self.image)
+                .ok()?
+            {
+                n += 1;
+            }
+        }
+        Some(n)
     }
 
-    pub fn boss_door_state_tier3(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
-        self.completion_pointers
-            .boss_door_state_tier3
-            .deref(process, &self.module, &self.image)
-            .ok()
+    /// Game completion percentage, computed the same way as the game's
+    /// PlayerData.CountGameCompletion, out of a maximum of 112
+    pub fn game_completion(&self, process: &Process) -> Option<i32> {
+        let pd = &self.player_data_pointers;
+        let get_i32 = |pointer: &UnityPointer<3>| -> Option<i32> {
+            pointer
+                .deref::<i32>(process, &self.module, &self.image)
+                .ok()
+        };
+        let values = CompletionValues {
+            charms_owned: self.charms_owned(process)?,
+            got_shade_charm: self.read_bools(process, [&pd.got_shade_charm])?[0],
+            bosses: self.read_bools(
+                process,
+                [
+                    &pd.killed_false_knight,
+                    &pd.hornet1_defeated,
+                    &pd.hornet_outskirts_defeated,
+                    &pd.defeated_mantis_lords,
+                    &pd.killed_mage_lord,
+                    &pd.killed_dung_defender,
+                    &pd.killed_black_knight,
+                    &pd.killed_infected_knight,
+                    &pd.killed_mimic_spider,
+                    &pd.killed_mega_jellyfish,
+                    &pd.killed_traitor_lord,
+                    &pd.collector_defeated,
+                    &pd.killed_big_fly,
+                    &pd.killed_mawlek,
+                    &pd.killed_hive_knight,
+                ],
+            )?,
+            colosseum: self.read_bools(
+                process,
+                [
+                    &pd.colosseum_bronze_completed,
+                    &pd.colosseum_silver_completed,
+                    &pd.colosseum_gold_completed,
+                ],
+            )?,
+            warrior_dreams: self.read_bools(
+                process,
+                [
+                    &pd.killed_ghost_aladar,
+                    &pd.killed_ghost_xero,
+                    &pd.killed_ghost_hu,
+                    &pd.killed_ghost_marmu,
+                    &pd.killed_ghost_no_eyes,
+                    &pd.killed_ghost_galien,
+                    &pd.killed_ghost_markoth,
+                ],
+            )?,
+            spell_levels: [
+                get_i32(&pd.fireball_level)?,
+                get_i32(&pd.quake_level)?,
+                get_i32(&pd.scream_level)?,
+            ],
+            nail_arts: self.read_bools(
+                process,
+                [&pd.has_cyclone, &pd.has_dash_slash, &pd.has_upward_slash],
+            )?,
+            equipment: self.read_bools(
+                process,
+                [
+                    &pd.has_dash,
+                    &pd.has_wall_jump,
+                    &pd.has_double_jump,
+                    &pd.has_acid_armour,
+                    &pd.has_super_dash,
+                    &pd.has_shadow_dash,
+                    &pd.has_kings_brand,
+                ],
+            )?,
+            dreamers: self.read_bools(
+                process,
+                [
+                    &pd.mask_broken_lurien,
+                    &pd.mask_broken_monomon,
+                    &pd.mask_broken_hegemol,
+                ],
+            )?,
+            dream_nail: self.read_bools(
+                process,
+                [
+                    &pd.has_dream_nail,
+                    &pd.dream_nail_upgraded,
+                    &pd.moth_departed,
+                ],
+            )?,
+            nail_smith_upgrades: get_i32(&pd.nail_smith_upgrades)?,
+            max_health_base: get_i32(&pd.max_health_base)?,
+            mp_reserve_max: get_i32(&pd.mp_reserve_max)?,
+            grimm: self.read_bools(
+                process,
+                [
+                    &pd.killed_grimm,
+                    &pd.killed_nightmare_grimm,
+                    &pd.destroyed_nightmare_lantern,
+                ],
+            )?,
+            pantheons: [
+                self.boss_door_state_tier1(process)?.completed,
+                self.boss_door_state_tier2(process)?.completed,
+                self.boss_door_state_tier3(process)?.completed,
+                self.boss_door_state_tier4(process)?.completed,
+            ],
+        };
+        Some(values.game_completion())
     }
 
-    pub fn killed_hollow_knight_prime(&self, process: &Process) -> Option<bool> {
-        self.player_data_pointers
-            .killed_hollow_knight_prime
-            .deref(process, &self.module, &self.image)
-            .ok()
+    fn read_bools<const N: usize>(
+        &self,
+        process: &Process,
+        pointers: [&UnityPointer<3>; N],
+    ) -> Option<[bool; N]> {
+        let mut bs = [false; N];
+        for (b, pointer) in bs.iter_mut().zip(pointers) {
+            *b = pointer
+                .deref::<bool>(process, &self.module, &self.image)
+                .ok()?;
+        }
+        Some(bs)
     }
+}
 
-    pub fn boss_door_state_tier4(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
-        self.completion_pointers
-            .boss_door_state_tier4
-            .deref(process, &self.module, &self.image)
-            .ok()
-    }
+/// The PlayerData values that count towards game completion
+#[derive(Default)]
+struct CompletionValues {
+    charms_owned: i32,
+    /// Void Heart
+    got_shade_charm: bool,
+    bosses: [bool; 15],
+    colosseum: [bool; 3],
+    warrior_dreams: [bool; 7],
+    /// Vengeful Spirit, Desolate Dive, and Howling Wraiths, each up to level 2
+    spell_levels: [i32; 3],
+    nail_arts: [bool; 3],
+    /// Each worth 2%
+    equipment: [bool; 7],
+    dreamers: [bool; 3],
+    /// Dream Nail, Awoken Dream Nail, and the Seer ascended
+    dream_nail: [bool; 3],
+    nail_smith_upgrades: i32,
+    max_health_base: i32,
+    mp_reserve_max: i32,
+    /// Grimm killed, then Nightmare King Grimm killed or the Troupe banished
+    grimm: [bool; 3],
+    /// Pantheons 1 through 4
+    pantheons: [bool; 4],
+}
 
-    pub fn boss_door_state_tier5(&self, process: &Process) -> Option<BossSequenceDoorCompletion> {
-        self.completion_pointers
-            .boss_door_state_tier5
-            .deref(process, &self.module, &self.image)
-            .ok()
+impl CompletionValues {
+    fn game_completion(&self) -> i32 {
+        let count = |bs: &[bool]| bs.iter().filter(|&&b| b).count() as i32;
+        let [killed_grimm, killed_nightmare_grimm, destroyed_nightmare_lantern] = self.grimm;
+        self.charms_owned
+            + self.got_shade_charm as i32
+            + count(&self.bosses)
+            + count(&self.colosseum)
+            + count(&self.warrior_dreams)
+            + self
+                .spell_levels
+                .iter()
+                .map(|&l| l.clamp(0, 2))
+                .sum::<i32>()
+            + count(&self.nail_arts)
+            + 2 * count(&self.equipment)
+            + count(&self.dreamers)
+            + count(&self.dream_nail)
+            + self.nail_smith_upgrades
+            + (self.max_health_base - 5)
+            + self.mp_reserve_max / 33
+            + killed_grimm as i32
+            + (killed_nightmare_grimm || destroyed_nightmare_lantern) as i32
+            + count(&self.pantheons)
     }
 }
 
@@ -4893,13 +5684,24 @@ pub struct SceneStore {
     prev_scene_name: String,
     curr_scene_name: String,
     next_scene_name: String,
-    all_scene_names: Vec<String>,
+    /// The names of every loaded scene, updated in place every tick
+    all_scene_names: Vec<SceneName>,
+    /// The last scene name read, to skip decoding it again when it hasn't changed
+    curr_scene_buf: Option<SceneName>,
+    /// The last next scene name read, to skip decoding it again when it hasn't changed
+    next_scene_buf: Option<SceneName>,
     new_data_curr: bool,
     new_data_next: bool,
     last_next: bool,
     pub split_this_transition: bool,
 }
 
+impl Default for SceneStore {
+    fn default() -> Self {
+        SceneStore::new()
+    }
+}
+
 impl SceneStore {
     pub fn new() -> SceneStore {
         SceneStore {
@@ -4907,6 +5709,8 @@ impl SceneStore {
             curr_scene_name: "".to_string(),
             next_scene_name: "".to_string(),
             all_scene_names: vec![],
+            curr_scene_buf: None,
+            next_scene_buf: None,
             new_data_curr: false,
             new_data_next: false,
             last_next: true,
@@ -4928,16 +5732,29 @@ impl SceneStore {
         }
     }
 
-    pub fn all_scene_names(&self) -> Vec<&str> {
-        self.all_scene_names.iter().map(|s| s.as_str()).collect()
+    /// Whether a scene with this name is loaded, such as an additively loaded boss scene
+    pub fn is_scene_loaded(&self, name: &str) -> bool {
+        self.all_scene_names.iter().any(|s| s.is(name))
+    }
+
+    /// Forgets any transition in progress when the game closes,
+    /// but keeps the scene names so that the game starting up again
+    /// looks like a transition from the last scene before it closed.
+    pub fn process_lost(&mut self) {
+        self.next_scene_name.clear();
+        self.all_scene_names.clear();
+        self.curr_scene_buf = None;
+        self.next_scene_buf = None;
+        self.new_data_curr = false;
+        self.new_data_next = false;
+        self.last_next = false;
     }
 
     pub fn new_curr_scene_name(&mut self, mcsn: Option<String>) {
         match mcsn {
             Some(csn) if csn != self.curr_scene_name => {
                 self.prev_scene_name = mem::replace(&mut self.curr_scene_name, csn);
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!("curr_scene_name: {}", self.curr_scene_name));
+                log!(Debug, "curr_scene_name: {}", self.curr_scene_name);
                 self.new_data_curr = self.curr_scene_name != self.next_scene_name;
             }
             _ => (),
@@ -4957,8 +5774,7 @@ impl SceneStore {
         match mnsn {
             Some(nsn) if nsn != self.next_scene_name => {
                 self.next_scene_name = nsn;
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!("next_scene_name: {}", self.next_scene_name));
+                log!(Debug, "next_scene_name: {}", self.next_scene_name);
                 self.new_data_next = !self.next_scene_name.is_empty();
             }
             _ => (),
@@ -4975,33 +5791,65 @@ impl SceneStore {
         }
     }
 
-    fn new_all_scene_names(&mut self, nasn: Vec<String>) -> bool {
-        if nasn != self.all_scene_names {
-            self.all_scene_names = nasn;
-            #[cfg(debug_assertions)]
-            asr::print_message(&format!("all_scene_names: {:?}", self.all_scene_names));
-            false
-        } else {
-            false
+    /// Takes the names of every loaded scene read this tick,
+    /// updating the stored names in place,
+    /// so that it only allocates when more scenes are loaded than ever before.
+    pub fn update_loaded_scenes(&mut self, names: impl IntoIterator<Item = SceneName>) {
+        let mut changed = false;
+        let mut k = 0;
+        for name in names {
+            match self.all_scene_names.get_mut(k) {
+                Some(old) if *old == name => (),
+                Some(old) => {
+                    *old = name;
+                    changed = true;
+                }
+                None => {
+                    self.all_scene_names.push(name);
+                    changed = true;
+                }
+            }
+            k += 1;
+        }
+        if k < self.all_scene_names.len() {
+            self.all_scene_names.truncate(k);
+            changed = true;
+        }
+        if changed {
+            log!(Trace, "all_scene_names: {:?}", self.all_scene_names);
         }
     }
 
+    fn update_all_scene_names(&mut self, prc: &Process, sm: &SceneManager) {
+        self.update_loaded_scenes(sm.scenes(prc).filter_map(|s| {
+            let path = s.path::<SCENE_PATH_SIZE>(prc, sm).ok()?;
+            SceneName::from_utf8(scene_manager::get_name(&path))
+        }));
+    }
+
     pub fn transition_now(
         &mut self,
         prc: &Process,
         msm: Option<&SceneManager>,
         g: &GameManagerFinder,
     ) -> bool {
-        self.new_curr_scene_name1(g.get_scene_name(prc));
-        self.new_next_scene_name1(g.get_next_scene_name(prc));
         if let Some(sm) = msm {
-            self.new_all_scene_names(
-                sm.scenes(prc)
-                    .filter_map(|s| {
-                        scene_path_to_name_string(s.path::<SCENE_PATH_SIZE>(prc, sm).ok()?)
-                    })
-                    .collect(),
-            );
+            self.update_all_scene_names(prc, sm);
+        }
+        self.transition_on(g.get_scene_name_buf(prc), g.get_next_scene_name_buf(prc))
+    }
+
+    /// Takes the scene name and next scene name read this tick,
+    /// and returns whether there's a transition now.
+    /// Only decodes a name into a String when it differs from the last one read.
+    pub fn transition_on(&mut self, curr: Option<SceneName>, next: Option<SceneName>) -> bool {
+        if curr != self.curr_scene_buf {
+            self.curr_scene_buf = curr;
+            self.new_curr_scene_name1(curr.and_then(|c| c.decode()));
+        }
+        if next != self.next_scene_buf {
+            self.next_scene_buf = next;
+            self.new_next_scene_name1(next.and_then(|n| n.decode()));
         }
 
         if self.new_data_next {
@@ -5009,11 +5857,12 @@ impl SceneStore {
             self.new_data_next = false;
             self.last_next = true;
             self.split_this_transition = false;
-            #[cfg(debug_assertions)]
-            asr::print_message(&format!(
+            log!(
+                Info,
                 "curr {} -> next {}",
-                &self.curr_scene_name, &self.next_scene_name
-            ));
+                &self.curr_scene_name,
+                &self.next_scene_name
+            );
             true
         } else if self.new_data_curr {
             self.new_data_curr = false;
@@ -5021,20 +5870,22 @@ impl SceneStore {
                 && !is_menu(&self.prev_scene_name)
                 && !is_menu(&self.curr_scene_name)
             {
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!(
+                log!(
+                    Debug,
                     "IGNORING spurious curr {} during next {}",
-                    self.curr_scene_name, self.next_scene_name
-                ));
+                    self.curr_scene_name,
+                    self.next_scene_name
+                );
                 return false;
             }
             self.last_next = false;
             self.split_this_transition = false;
-            #[cfg(debug_assertions)]
-            asr::print_message(&format!(
+            log!(
+                Info,
                 "prev {} -> curr {}",
-                &self.prev_scene_name, &self.curr_scene_name
-            ));
+                &self.prev_scene_name,
+                &self.curr_scene_name
+            );
             true
         } else {
             false
@@ -5042,16 +5893,80 @@ impl SceneStore {
     }
 }
 
+/// Values by key, for the few dozen keys a run looks at,
+/// where the keys are almost always the same string literals.
+///
+/// Looks a key up by its pointer before comparing strings,
+/// so that the lookups every tick don't walk a tree comparing strings.
+struct KeyedValues<T> {
+    entries: Vec<(Cow<'static, str>, T)>,
+}
+
+impl<T> KeyedValues<T> {
+    fn new() -> KeyedValues<T> {
+        KeyedValues {
+            entries: Vec::new(),
+        }
+    }
+
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(k, _)| core::ptr::eq(k.as_ref(), key))
+            .or_else(|| self.entries.iter().position(|(k, _)| k == key))
+    }
+
+    fn get(&self, key: &str) -> Option<&T> {
+        self.position(key).map(|i| &self.entries[i].1)
+    }
+
+    /// Keeps the literal key when replacing a key restored from a snapshot,
+    /// so that the next lookup finds it by pointer
+    fn insert(&mut self, key: Cow<'static, str>, value: T) {
+        match self.position(&key) {
+            Some(i) => {
+                if let Cow::Borrowed(_) = key {
+                    self.entries[i].0 = key;
+                }
+                self.entries[i].1 = value;
+            }
+            None => self.entries.push((key, value)),
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> Option<T> {
+        self.position(key).map(|i| self.entries.swap_remove(i).1)
+    }
+
+    fn retain(&mut self, mut f: impl FnMut(&str, &T) -> bool) {
+        self.entries.retain(|(k, v)| f(k, v));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.entries.iter().map(|(k, v)| (k.as_ref(), v))
+    }
+}
+
 pub struct PlayerDataStore {
-    map_i32: BTreeMap<&'static str, i32>,
-    map_bool: BTreeMap<&'static str, bool>,
+    map_i32: KeyedValues<i32>,
+    map_bool: KeyedValues<bool>,
+}
+
+impl Default for PlayerDataStore {
+    fn default() -> Self {
+        PlayerDataStore::new()
+    }
 }
 
 impl PlayerDataStore {
     pub fn new() -> PlayerDataStore {
         PlayerDataStore {
-            map_i32: BTreeMap::new(),
-            map_bool: BTreeMap::new(),
+            map_i32: KeyedValues::new(),
+            map_bool: KeyedValues::new(),
         }
     }
     pub fn reset(&mut self) {
@@ -5063,6 +5978,46 @@ impl PlayerDataStore {
         self.map_bool.retain(|k, _| !k.ends_with("_on_entry"));
     }
 
+    /// Writes the stored values into a run snapshot,
+    /// as lists of key-value pairs.
+    pub fn snapshot_into(&self, snapshot: &asr::settings::Map) {
+        let i32s = asr::settings::List::new();
+        for (k, v) in self.map_i32.iter() {
+            let e = asr::settings::List::new();
+            e.push(k);
+            e.push(*v as i64);
+            i32s.push(e);
+        }
+        snapshot.insert("player_data_i32", i32s);
+        let bools = asr::settings::List::new();
+        for (k, v) in self.map_bool.iter() {
+            let e = asr::settings::List::new();
+            e.push(k);
+            e.push(*v);
+            bools.push(e);
+        }
+        snapshot.insert("player_data_bool", bools);
+    }
+
+    /// Replaces the stored values with the ones from a run snapshot
+    pub fn restore_from(&mut self, snapshot: &asr::settings::Map) {
+        self.reset();
+        if let Some(l) = snapshot.get("player_data_i32").and_then(|v| v.get_list()) {
+            for e in l.iter() {
+                if let Some((k, v)) = snapshot_entry(&e, |v| v.get_i64()) {
+                    self.map_i32.insert(k.into(), v as i32);
+                }
+            }
+        }
+        if let Some(l) = snapshot.get("player_data_bool").and_then(|v| v.get_list()) {
+            for e in l.iter() {
+                if let Some((k, v)) = snapshot_entry(&e, |v| v.get_bool()) {
+                    self.map_bool.insert(k.into(), v);
+                }
+            }
+        }
+    }
+
     fn get_bool<const N: usize>(
         &mut self,
         p: &Process,
@@ -5073,10 +6028,10 @@ impl PlayerDataStore {
         if !g.is_game_state_non_menu(p) {
             return self.map_bool.get(key).copied();
         };
-        let Ok(b) = pointer.deref(p, &g.module, &g.image) else {
+        let Some(b) = g.read_pointer(p, key, pointer) else {
             return self.map_bool.get(key).copied();
         };
-        self.map_bool.insert(key, b);
+        self.map_bool.insert(key.into(), b);
         Some(b)
     }
 
@@ -5086,18 +6041,27 @@ impl PlayerDataStore {
         g: &GameManagerFinder,
         key: &'static str,
         pointer: &UnityPointer<N>,
+    ) -> Option<bool> {
+        let val = g.read_pointer(p, key, pointer)?;
+        self.store_changed_bool(key, val, g.is_game_state_non_menu(p))
+    }
+
+    /// The part of `changed_bool` after reading the value,
+    /// public for the tick path benchmark in `examples/tick_bench.rs`
+    pub fn store_changed_bool(
+        &mut self,
+        key: &'static str,
+        val: bool,
+        non_menu: bool,
     ) -> Option<bool> {
         let store_val = self.map_bool.get(key).copied();
-        let player_data_val = pointer.deref(p, &g.module, &g.image).ok();
-        if let Some(val) = player_data_val {
-            if val || g.is_game_state_non_menu(p) {
-                self.map_bool.insert(key, val);
-            }
+        if val || non_menu {
+            self.map_bool.insert(key.into(), val);
         }
-        if player_data_val? == store_val? {
+        if val == store_val? {
             return None;
         }
-        player_data_val
+        Some(val)
     }
 
     fn get_i32<const N: usize>(
@@ -5110,10 +6074,10 @@ impl PlayerDataStore {
         if !g.is_game_state_non_menu(p) {
             return self.map_i32.get(key).copied();
         };
-        let Ok(i) = pointer.deref(p, &g.module, &g.image) else {
+        let Some(i) = g.read_pointer(p, key, pointer) else {
             return self.map_i32.get(key).copied();
         };
-        self.map_i32.insert(key, i);
+        self.map_i32.insert(key.into(), i);
         Some(i)
     }
 
@@ -5124,10 +6088,21 @@ impl PlayerDataStore {
         key: &'static str,
         pointer: &UnityPointer<N>,
     ) -> Option<Pair<i32>> {
-        let store_val = self.map_i32.get(key).cloned();
-        let current = pointer.deref(p, &g.module, &g.image).ok()?;
-        if current != 0 || g.is_game_state_non_menu(p) {
-            self.map_i32.insert(key, current);
+        let current = g.read_pointer(p, key, pointer)?;
+        self.store_changed_i32(key, current, g.is_game_state_non_menu(p))
+    }
+
+    /// The part of `changed_i32` after reading the value,
+    /// public for the tick path benchmark in `examples/tick_bench.rs`
+    pub fn store_changed_i32(
+        &mut self,
+        key: &'static str,
+        current: i32,
+        non_menu: bool,
+    ) -> Option<Pair<i32>> {
+        let store_val = self.map_i32.get(key).copied();
+        if current != 0 || non_menu {
+            self.map_i32.insert(key.into(), current);
         }
         let old = store_val?;
         if current != old {
@@ -5181,16 +6156,14 @@ impl PlayerDataStore {
             .is_some_and(|d| d == -1)
     }
 
-    #[cfg(debug_assertions)]
     pub fn get_game_state(&mut self, p: &Process, g: &GameManagerFinder) -> i32 {
-        let Ok(i) = g.pointers.game_state.deref(p, &g.module, &g.image) else {
+        let Some(i) = g.read_pointer(p, "game_state", &g.pointers.game_state) else {
             return self.map_i32.get("game_state").copied().unwrap_or(0);
         };
-        #[cfg(debug_assertions)]
         if self.map_i32.get("game_state").is_some_and(|&old| old != i) {
-            asr::print_message(&format!("game_state: {}", i));
+            log!(Debug, "game_state: {}", i);
         }
-        self.map_i32.insert("game_state", i);
+        self.map_i32.insert("game_state".into(), i);
         i
     }
 
@@ -5359,7 +6332,7 @@ impl PlayerDataStore {
                 .unwrap_or(&false)
                 .clone();
         };
-        self.map_bool.insert("sly_shop_finished", b);
+        self.map_bool.insert("sly_shop_finished".into(), b);
         b
     }
 
@@ -5378,10 +6351,11 @@ impl PlayerDataStore {
             None => false,
             Some(false) => {
                 if let Some(health) = g.get_health(p) {
-                    self.map_i32.insert("health_before_focus", health);
+                    self.map_i32.insert("health_before_focus".into(), health);
                 }
                 if let Some(mpcharge) = g.get_mpcharge(p) {
-                    self.map_i32.insert("mpcharge_before_focus", mpcharge);
+                    self.map_i32
+                        .insert("mpcharge_before_focus".into(), mpcharge);
                 }
                 false
             }
@@ -5478,7 +6452,7 @@ impl PlayerDataStore {
                 .unwrap_or(&false)
                 .clone();
         };
-        self.map_bool.insert("grub_waterways_isma", b);
+        self.map_bool.insert("grub_waterways_isma".into(), b);
         b
     }
 
@@ -5495,6 +6469,18 @@ impl PlayerDataStore {
         )
     }
 
+    /// Whether the Delicate Flower broke since the last time this was called,
+    /// not whether it's broken already, such as from earlier in the save
+    pub fn flower_broken(&mut self, p: &Process, g: &GameManagerFinder) -> bool {
+        self.changed_bool(
+            p,
+            g,
+            "xun_flower_broken",
+            &g.player_data_pointers.xun_flower_broken,
+        )
+        .is_some_and(|b| b)
+    }
+
     pub fn changed_travelling_true(
         &mut self,
         process: &Process,
@@ -5665,7 +6651,7 @@ impl PlayerDataStore {
         changed: bool,
     ) -> i32 {
         let glade_essence_since_changed = if changed {
-            self.map_i32.insert("glade_essence_since_changed", 0);
+            self.map_i32.insert("glade_essence_since_changed".into(), 0);
             0
         } else if let Some(&glade_essence_since_changed) =
             self.map_i32.get("glade_essence_since_changed")
@@ -5676,12 +6662,12 @@ impl PlayerDataStore {
         };
         let o = self.incremented_dream_orbs(process, game_manager_finder);
         if o && game_manager_finder
-            .get_scene_name(process)
+            .get_scene_name_buf(process)
             .is_some_and(|s| s.starts_with("RestingGrounds_08"))
         {
             let next_glade_essence = glade_essence_since_changed + 1;
             self.map_i32
-                .insert("glade_essence_since_changed", next_glade_essence);
+                .insert("glade_essence_since_changed".into(), next_glade_essence);
             next_glade_essence
         } else {
             glade_essence_since_changed
@@ -5733,7 +6719,7 @@ impl PlayerDataStore {
                     &gmf.image,
                 ) {
                     self.map_bool
-                        .insert("dung_defender_awake_convo_on_entry", convo_now);
+                        .insert("dung_defender_awake_convo_on_entry".into(), convo_now);
                     convo_now
                 } else {
                     false
@@ -5903,8 +6889,8 @@ impl PlayerDataStore {
         }
         match self.map_bool.get(key) {
             None | Some(false) => {
-                if let Ok(dead_now) = pointer.deref(prc, &gmf.module, &gmf.image) {
-                    self.map_bool.insert(key, dead_now);
+                if let Some(dead_now) = gmf.read_pointer(prc, key, pointer) {
+                    self.map_bool.insert(key.into(), dead_now);
                 }
                 false
             }
@@ -5952,8 +6938,8 @@ impl PlayerDataStore {
         }
         match self.map_i32.get(key) {
             None => {
-                let kills_now = pointer.deref(prc, &gmf.module, &gmf.image).ok()?;
-                self.map_i32.insert(key, kills_now);
+                let kills_now = gmf.read_pointer(prc, key, pointer)?;
+                self.map_i32.insert(key.into(), kills_now);
                 Some(kills_now)
             }
             Some(k) => Some(*k),
@@ -5977,7 +6963,7 @@ impl PlayerDataStore {
                 if !gmf.is_game_state_non_menu(prc) {
                     return None;
                 }
-                let kills_now: i32 = pointer.deref(prc, &gmf.module, &gmf.image).ok()?;
+                let kills_now: i32 = gmf.read_pointer(prc, key, pointer)?;
                 if kills_now + d <= kills_on_entry {
                     Some(true)
                 } else if kills_now == 0 {
@@ -7302,8 +8288,108 @@ impl PlayerDataStore {
 
 // --------------------------------------------------------
 
+/// Reads a key-value pair written by `PlayerDataStore::snapshot_into`
+fn snapshot_entry<T>(
+    e: &asr::settings::Value,
+    get: impl FnOnce(&asr::settings::Value) -> Option<T>,
+) -> Option<(String, T)> {
+    let l = e.get_list()?;
+    let mut it = l.iter();
+    let k = it.next()?.get_string()?;
+    let v = get(&it.next()?)?;
+    Some((k, v))
+}
+
+/// A persistent item that a split looks at,
+/// along with where it was found in its list,
+/// so that the list is only walked again when a save is loaded,
+/// when the item isn't there yet and an item is added as a scene is saved,
+/// or when saving the scene replaced the item in its slot.
+struct TrackedItem<T> {
+    scene: String,
+    id: String,
+    /// The list the item was last looked for in
+    list: Address,
+    /// The size of that list when the item wasn't in it
+    size: u32,
+    /// The item's index and address in that list, or None if it wasn't in it
+    item: Option<(u64, Address)>,
+    /// The value the last time it was read, or Some(None) if it wasn't in the list
+    last: Option<Option<T>>,
+}
+
+impl<T: CheckedBitPattern> TrackedItem<T> {
+    fn new(scene: &str, id: &str) -> TrackedItem<T> {
+        TrackedItem {
+            scene: scene.to_string(),
+            id: id.to_string(),
+            list: Address::NULL,
+            size: 0,
+            item: None,
+            last: None,
+        }
+    }
+
+    /// Finds the tracked item with the given scene and id,
+    /// only allocating the first time it's looked for.
+    fn find<'a>(items: &'a mut Vec<TrackedItem<T>>, scene: &str, id: &str) -> &'a mut Self {
+        let k = items
+            .iter()
+            .position(|t| t.scene == scene && t.id == id)
+            .unwrap_or_else(|| {
+                items.push(TrackedItem::new(scene, id));
+                items.len() - 1
+            });
+        &mut items[k]
+    }
+
+    /// Looks for the item again on the next read
+    fn forget_address(&mut self) {
+        self.list = Address::NULL;
+        self.size = 0;
+        self.item = None;
+    }
+
+    /// Reads the value of the item, or Some(None) if the list doesn't contain it yet
+    fn read(
+        &mut self,
+        prc: &Process,
+        gmf: &GameManagerFinder,
+        list: &PersistentItemList,
+    ) -> Option<Option<T>> {
+        let offsets = &gmf.string_list_offests;
+        let items = gmf.deref_pointer(prc, list.pointer).ok()?;
+        let size = list_object_size(prc, offsets, items)?;
+        let moved = match self.item {
+            // SaveMyState replaces the item in its slot with a new object
+            Some((i, item)) => list_object_item(prc, offsets, items, i) != Some(item),
+            None => size != self.size,
+        };
+        if items != self.list || moved {
+            let (scene, id) = (&self.scene, &self.id);
+            self.item =
+                walk_persistent_items(prc, gmf, items, list, |i, item, item_scene, item_id| {
+                    (item_scene == *scene && item_id == *id).then_some((i, item))
+                })?;
+            self.list = items;
+            self.size = size;
+        }
+        let Some((_, item)) = self.item else {
+            return Some(None);
+        };
+        let Ok(value) = prc.read(item + list.value) else {
+            // look for it again next time
+            self.list = Address::NULL;
+            return None;
+        };
+        Some(Some(value))
+    }
+}
+
 pub struct SceneDataStore {
     map_bool_items: BTreeMap<(String, String), bool>,
+    bool_items: Vec<TrackedItem<bool>>,
+    int_items: Vec<TrackedItem<i32>>,
     map_bool_derived: BTreeMap<&'static str, bool>,
     map_i32_derived: BTreeMap<&'static str, i32>,
 }
@@ -7312,12 +8398,32 @@ impl SceneDataStore {
     pub fn new() -> SceneDataStore {
         SceneDataStore {
             map_bool_items: BTreeMap::new(),
+            bool_items: Vec::new(),
+            int_items: Vec::new(),
             map_bool_derived: BTreeMap::new(),
             map_i32_derived: BTreeMap::new(),
         }
     }
     pub fn reset(&mut self) {
         self.map_bool_items.clear();
+        for t in self.bool_items.iter_mut() {
+            t.last = None;
+        }
+        for t in self.int_items.iter_mut() {
+            t.last = None;
+        }
+    }
+
+    /// Forgets where the tracked items were, when the game process is lost or attached again,
+    /// since those addresses belong to the old process.
+    /// Keeps their values, so that the game starting up again looks like a quitout.
+    pub fn detached(&mut self) {
+        for t in self.bool_items.iter_mut() {
+            t.forget_address();
+        }
+        for t in self.int_items.iter_mut() {
+            t.forget_address();
+        }
     }
 
     pub fn glade_ghosts_killed(
@@ -7371,8 +8477,7 @@ impl SceneDataStore {
                 .is_some_and(|&prev_activated| prev_activated == activated)
             {
                 changed = true;
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!("SceneData {:?}: {}", key, activated));
+                log!(Debug, "SceneData {:?}: {}", key, activated);
                 self.map_bool_items.insert(key, activated);
             }
         }
@@ -7385,8 +8490,7 @@ impl SceneDataStore {
             .is_some_and(|&prev_alone| prev_alone == revek_alone)
         {
             changed = true;
-            #[cfg(debug_assertions)]
-            asr::print_message(&format!("SceneData revek_alone: {}", revek_alone));
+            log!(Debug, "SceneData revek_alone: {}", revek_alone);
             self.map_bool_derived.insert("revek_alone", revek_alone);
         }
         if !self
@@ -7395,12 +8499,204 @@ impl SceneDataStore {
             .is_some_and(|&prev_killed| prev_killed == killed)
         {
             changed = true;
-            #[cfg(debug_assertions)]
-            asr::print_message(&format!("SceneData glade_ghosts_killed: {}", killed));
+            log!(Debug, "SceneData glade_ghosts_killed: {}", killed);
             self.map_i32_derived.insert("glade_ghosts_killed", killed);
         }
         Some((changed, revek_alone, killed))
     }
+
+    /// Whether the persistent bool item with the given scene and id
+    /// became activated since the last time this was called for it.
+    /// An item that isn't in the list yet counts as not activated.
+    pub fn persistent_bool_item_became_activated(
+        &mut self,
+        prc: &Process,
+        gmf: &GameManagerFinder,
+        scene: &str,
+        id: &str,
+    ) -> bool {
+        let Some(list) = gmf
+            .scene_data_pointers
+            .bool_item_list(prc, &gmf.module, &gmf.image)
+        else {
+            return false;
+        };
+        let t = TrackedItem::find(&mut self.bool_items, scene, id);
+        let Some(activated) = t.read(prc, gmf, &list) else {
+            return false;
+        };
+        gmf.record_read("scene data bool", (scene, id, activated));
+        let prev_activated = t.last.replace(activated).map(|a| a.unwrap_or(false));
+        let activated = activated.unwrap_or(false);
+        if prev_activated != Some(activated) {
+            log!(Debug, "SceneData ({:?}, {:?}): {}", scene, id, activated);
+        }
+        prev_activated == Some(false) && activated
+    }
+
+    /// Whether the persistent int item with the given scene and id
+    /// reached or crossed the target value since the last time this was called for it.
+    /// An item that appears in the list for the first time counts if it's at or past the target.
+    pub fn persistent_int_item_reached(
+        &mut self,
+        prc: &Process,
+        gmf: &GameManagerFinder,
+        scene: &str,
+        id: &str,
+        target: i32,
+    ) -> bool {
+        let Some(list) = gmf
+            .scene_data_pointers
+            .int_item_list(prc, &gmf.module, &gmf.image)
+        else {
+            return false;
+        };
+        let t = TrackedItem::find(&mut self.int_items, scene, id);
+        let Some(value) = t.read(prc, gmf, &list) else {
+            return false;
+        };
+        gmf.record_read("scene data int", (scene, id, value));
+        let prev_value = t.last.replace(value);
+        if prev_value != Some(value) {
+            log!(Debug, "SceneData ({:?}, {:?}): {:?}", scene, id, value);
+        }
+        persistent_int_item_crossed(prev_value, value, target)
+    }
+
+    /// Prints all of the persistent items currently in the scene data,
+    /// so that route makers can find the scene and id of an item to split on.
+    pub fn log_persistent_items(&self, prc: &Process, gmf: &GameManagerFinder) {
+        let sdp = &gmf.scene_data_pointers;
+        let bool_items = sdp
+            .bool_item_list(prc, &gmf.module, &gmf.image)
+            .and_then(|list| persistent_items::<bool>(prc, gmf, &list));
+        if let Some(items) = bool_items {
+            asr::print_message(&format!(
+                "SceneData persistentBoolItems: {} items",
+                items.len()
+            ));
+            for (scene, id, activated) in items {
+                asr::print_message(&format!("SceneData ({:?}, {:?}): {}", scene, id, activated));
+            }
+        } else {
+            asr::print_message("SceneData persistentBoolItems: not found");
+        }
+        let int_items = sdp
+            .int_item_list(prc, &gmf.module, &gmf.image)
+            .and_then(|list| persistent_items::<i32>(prc, gmf, &list));
+        if let Some(items) = int_items {
+            asr::print_message(&format!(
+                "SceneData persistentIntItems: {} items",
+                items.len()
+            ));
+            for (scene, id, value) in items {
+                asr::print_message(&format!("SceneData ({:?}, {:?}): {}", scene, id, value));
+            }
+        } else {
+            asr::print_message("SceneData persistentIntItems: not found");
+        }
+    }
+}
+
+/// The scene, id, and value of every persistent bool or int item,
+/// to find the first item that changes when taking an item for a Scene Data split
+pub struct PersistentItemValues(Vec<(String, String, i32)>);
+
+impl PersistentItemValues {
+    /// Reads the int items if `ints`, or the bool items as 0 or 1 otherwise
+    pub fn read(
+        prc: &Process,
+        gmf: &GameManagerFinder,
+        ints: bool,
+    ) -> Option<PersistentItemValues> {
+        let sdp = &gmf.scene_data_pointers;
+        let values = if ints {
+            let list = sdp.int_item_list(prc, &gmf.module, &gmf.image)?;
+            persistent_items::<i32>(prc, gmf, &list)?
+        } else {
+            let list = sdp.bool_item_list(prc, &gmf.module, &gmf.image)?;
+            persistent_items::<bool>(prc, gmf, &list)?
+                .into_iter()
+                .map(|(scene, id, b)| (scene, id, b as i32))
+                .collect()
+        };
+        Some(PersistentItemValues(values))
+    }
+
+    /// The first item with a different value than in `old`, or that isn't in `old`
+    pub fn changed_since(&self, old: &PersistentItemValues) -> Option<(&str, &str, i32)> {
+        self.0.iter().find_map(|(scene, id, value)| {
+            let same = old
+                .0
+                .iter()
+                .any(|(s, i, v)| s == scene && i == id && v == value);
+            (!same).then_some((scene.as_str(), id.as_str(), *value))
+        })
+    }
+}
+
+/// Reads the scene, id, and value of every persistent item in the list
+fn persistent_items<T: CheckedBitPattern>(
+    prc: &Process,
+    gmf: &GameManagerFinder,
+    list: &PersistentItemList,
+) -> Option<Vec<(String, String, T)>> {
+    let items = gmf.deref_pointer(prc, list.pointer).ok()?;
+    let mut values = Vec::new();
+    let failed = walk_persistent_items(prc, gmf, items, list, |_, item, scene, id| {
+        match prc.read(item + list.value) {
+            Ok(value) => {
+                values.push((scene, id, value));
+                None
+            }
+            Err(_) => Some(()),
+        }
+    })?;
+    failed.is_none().then_some(values)
+}
+
+/// Walks the persistent item list at `items`,
+/// calling `f` with the index, address, scene, and id of each item until it produces Some.
+/// Produces Some(None) if it never did, or None if the list couldn't be read.
+fn walk_persistent_items<T>(
+    prc: &Process,
+    gmf: &GameManagerFinder,
+    items: Address,
+    list: &PersistentItemList,
+    mut f: impl FnMut(u64, Address, String, String) -> Option<T>,
+) -> Option<Option<T>> {
+    let offsets = &gmf.string_list_offests;
+    for (i, item) in list_object_indexed_iter(prc, offsets, items)? {
+        if item.is_null() {
+            continue;
+        }
+        let scene_addr = prc
+            .read_pointer(item + list.scene_name, offsets.pointer_size)
+            .ok()?;
+        let scene = read_string_object(prc, offsets, scene_addr)?;
+        let id_addr = prc
+            .read_pointer(item + list.id, offsets.pointer_size)
+            .ok()?;
+        let id = read_string_object(prc, offsets, id_addr)?;
+        if let Some(t) = f(i, item, scene, id) {
+            return Some(Some(t));
+        }
+    }
+    Some(None)
+}
+
+/// Whether a persistent int item reached or crossed the target value,
+/// from the value the last time it was looked for, to the value now.
+/// An item that appears in the list for the first time counts if it's at or past the target,
+/// since items appear when their scene is saved, often already past it.
+fn persistent_int_item_crossed(prev: Option<Option<i32>>, value: Option<i32>, target: i32) -> bool {
+    match (prev, value) {
+        (Some(Some(prev)), Some(new)) => {
+            (prev < target && target <= new) || (new <= target && target < prev)
+        }
+        (Some(None), Some(new)) => target <= new,
+        _ => false,
+    }
 }
 
 // --------------------------------------------------------
@@ -7409,10 +8705,6 @@ pub fn attach_hollow_knight() -> Option<Process> {
     HOLLOW_KNIGHT_NAMES.into_iter().find_map(Process::attach)
 }
 
-fn scene_path_to_name_string<const N: usize>(scene_path: ArrayCString<N>) -> Option<String> {
-    String::from_utf8(scene_manager::get_name(&scene_path).to_vec()).ok()
-}
-
 fn read_string_object(
     process: &Process,
     offsets: &StringListOffsets,
@@ -7428,11 +8720,37 @@ fn read_string_object(
     String::from_utf16(&w).ok()
 }
 
+fn read_string_object_buf(
+    process: &Process,
+    offsets: &StringListOffsets,
+    a: Address,
+) -> Option<SceneName> {
+    let n: u32 = process.read(a + offsets.string_len).ok()?;
+    if SCENE_NAME_SIZE < n as usize {
+        return None;
+    }
+    let mut name = SceneName::default();
+    process
+        .read_into_slice(a + offsets.string_contents, &mut name.buf[..n as usize])
+        .ok()?;
+    name.len = n as usize;
+    Some(name)
+}
+
 fn list_object_iter<'a>(
     process: &'a Process,
     offsets: &'a StringListOffsets,
     a: Address,
 ) -> Option<impl FusedIterator<Item = Address> + 'a> {
+    Some(list_object_indexed_iter(process, offsets, a)?.map(|(_, item)| item))
+}
+
+/// Like `list_object_iter`, along with the index of each item
+fn list_object_indexed_iter<'a>(
+    process: &'a Process,
+    offsets: &'a StringListOffsets,
+    a: Address,
+) -> Option<impl FusedIterator<Item = (u64, Address)> + 'a> {
     let array_ptr: Address = process
         .read_pointer(a + offsets.list_array, offsets.pointer_size)
         .ok()?;
@@ -7440,16 +8758,40 @@ fn list_object_iter<'a>(
 
     Some(
         (0..(vn as u64))
-            .filter_map(move |i| {
-                let item_offset = offsets.array_contents + (offsets.pointer_size as u64) * i;
-                process
-                    .read_pointer(array_ptr + item_offset, offsets.pointer_size)
-                    .ok()
-            })
+            .filter_map(move |i| Some((i, list_array_item(process, offsets, array_ptr, i)?)))
             .fuse(),
     )
 }
 
+/// Reads the item at index `i` of the list at `a`
+fn list_object_item(
+    process: &Process,
+    offsets: &StringListOffsets,
+    a: Address,
+    i: u64,
+) -> Option<Address> {
+    let array_ptr: Address = process
+        .read_pointer(a + offsets.list_array, offsets.pointer_size)
+        .ok()?;
+    list_array_item(process, offsets, array_ptr, i)
+}
+
+fn list_array_item(
+    process: &Process,
+    offsets: &StringListOffsets,
+    array_ptr: Address,
+    i: u64,
+) -> Option<Address> {
+    let item_offset = offsets.array_contents + (offsets.pointer_size as u64) * i;
+    process
+        .read_pointer(array_ptr + item_offset, offsets.pointer_size)
+        .ok()
+}
+
+fn list_object_size(process: &Process, offsets: &StringListOffsets, a: Address) -> Option<u32> {
+    process.read(a + offsets.list_size).ok()
+}
+
 fn read_string_list_object(
     process: &Process,
     offsets: &StringListOffsets,
@@ -7470,7 +8812,7 @@ fn read_string_list_object(
 // --------------------------------------------------------
 
 pub fn is_menu(s: &str) -> bool {
-    s.is_empty() || s == MENU_TITLE || s == QUIT_TO_MENU || s == PERMA_DEATH
+    s.is_empty() || MENU_SCENES.contains(&s)
 }
 
 /// Produces true on Dream scenes and Godhome scenes,
@@ -7501,3 +8843,115 @@ pub fn starts_with_any(full: &str, prefixes: &[&str]) -> bool {
 }
 
 // --------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keyed_values_find_keys_by_pointer_or_string() {
+        let mut values = KeyedValues::new();
+        values.insert("heart_pieces".into(), 1);
+        values.insert(String::from("vessel_fragments").into(), 2);
+        assert_eq!(values.get("heart_pieces"), Some(&1));
+        assert_eq!(values.get(&String::from("heart_pieces")), Some(&1));
+        assert_eq!(values.get("vessel_fragments"), Some(&2));
+        // a literal key replaces the restored one, keeping the value slot
+        values.insert("vessel_fragments".into(), 3);
+        assert_eq!(values.iter().count(), 2);
+        assert!(matches!(values.entries[1].0, Cow::Borrowed(_)));
+        assert_eq!(values.remove("heart_pieces"), Some(1));
+        assert_eq!(values.get("heart_pieces"), None);
+        assert_eq!(values.get("vessel_fragments"), Some(&3));
+    }
+
+    #[test]
+    fn persistent_item_values_find_the_changed_item() {
+        let item = |scene: &str, id: &str, value| (scene.to_string(), id.to_string(), value);
+        let old = PersistentItemValues(vec![
+            item("Crossroads_01", "Breakable Wall", 0),
+            item("Ruins2_04", "Geo Rock 1", 5),
+        ]);
+        assert_eq!(old.changed_since(&old), None);
+        let changed = PersistentItemValues(vec![
+            item("Crossroads_01", "Breakable Wall", 0),
+            item("Ruins2_04", "Geo Rock 1", 4),
+        ]);
+        assert_eq!(
+            changed.changed_since(&old),
+            Some(("Ruins2_04", "Geo Rock 1", 4))
+        );
+        // items appear in the list when their scene is saved
+        let appeared = PersistentItemValues(vec![
+            item("Crossroads_01", "Breakable Wall", 0),
+            item("Ruins2_04", "Geo Rock 1", 5),
+            item("Town", "Lever", 1),
+        ]);
+        assert_eq!(appeared.changed_since(&old), Some(("Town", "Lever", 1)));
+    }
+
+    #[test]
+    fn persistent_int_item_crossing_target() {
+        // increasing through the target
+        assert!(persistent_int_item_crossed(Some(Some(1)), Some(2), 2));
+        assert!(persistent_int_item_crossed(Some(Some(1)), Some(3), 2));
+        // decreasing through the target
+        assert!(persistent_int_item_crossed(Some(Some(3)), Some(2), 2));
+        assert!(persistent_int_item_crossed(Some(Some(3)), Some(1), 2));
+        // not reaching or staying at the target
+        assert!(!persistent_int_item_crossed(Some(Some(0)), Some(1), 2));
+        assert!(!persistent_int_item_crossed(Some(Some(2)), Some(2), 2));
+        assert!(!persistent_int_item_crossed(Some(Some(2)), Some(3), 2));
+    }
+
+    #[test]
+    fn persistent_int_item_appearing() {
+        // items appear when their scene is saved, often already past the target
+        assert!(persistent_int_item_crossed(Some(None), Some(2), 2));
+        assert!(persistent_int_item_crossed(Some(None), Some(4), 2));
+        assert!(!persistent_int_item_crossed(Some(None), Some(1), 2));
+        assert!(!persistent_int_item_crossed(Some(None), None, 2));
+    }
+
+    #[test]
+    fn persistent_int_item_first_look() {
+        // the first look has nothing to compare against
+        assert!(!persistent_int_item_crossed(None, Some(2), 2));
+        assert!(!persistent_int_item_crossed(None, None, 2));
+        // an item that disappears doesn't count
+        assert!(!persistent_int_item_crossed(Some(Some(1)), None, 2));
+    }
+
+    #[test]
+    fn game_completion_of_a_new_save_and_a_full_save() {
+        let new_save = CompletionValues {
+            max_health_base: 5,
+            ..Default::default()
+        };
+        assert_eq!(new_save.game_completion(), 0);
+        let full_save = CompletionValues {
+            charms_owned: 40,
+            got_shade_charm: true,
+            bosses: [true; 15],
+            colosseum: [true; 3],
+            warrior_dreams: [true; 7],
+            spell_levels: [2; 3],
+            nail_arts: [true; 3],
+            equipment: [true; 7],
+            dreamers: [true; 3],
+            dream_nail: [true; 3],
+            nail_smith_upgrades: 4,
+            max_health_base: 9,
+            mp_reserve_max: 99,
+            grimm: [true, true, false],
+            pantheons: [true; 4],
+        };
+        assert_eq!(full_save.game_completion(), 112);
+        // banishing the troupe counts the same as killing Nightmare King Grimm
+        let banished = CompletionValues {
+            grimm: [true, false, true],
+            ..full_save
+        };
+        assert_eq!(banished.game_completion(), 112);
+    }
+}
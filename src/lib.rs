@@ -8,19 +8,42 @@ pub static ALLOC: dlmalloc::GlobalDlmalloc = dlmalloc::GlobalDlmalloc;
 extern crate alloc;
 
 mod asr_xml;
+mod attach_status;
 mod auto_splitter_settings;
+mod branches;
+mod death_stats;
+mod deferred;
+mod diagnostics;
 #[cfg(not(target_os = "unknown"))]
 mod file;
+mod flower_quest;
+mod game_patch;
+mod game_state_vars;
 mod game_time;
 mod hit_counter;
+mod hit_penalty;
 mod hollow_knight_memory;
 mod legacy_xml;
 mod load_remover;
+mod log_level;
+mod next_split;
+mod occurrence;
+mod play_time;
+pub mod presets;
+mod resource_tracker;
+mod run_snapshot;
 mod settings_gui;
+mod split_arming;
+mod split_delay;
+mod split_entry;
+mod split_wrappers;
 pub mod splits;
 mod timer;
 mod unstable;
 
+/// Scene name and player data tracking, for the tick path benchmark in examples
+pub use hollow_knight_memory::{PlayerDataStore, SceneName, SceneStore};
+
 use alloc::boxed::Box;
 use alloc::format;
 use alloc::vec::Vec;
@@ -28,11 +51,30 @@ use alloc::vec::Vec;
 use asr::future::{next_tick, retry};
 use asr::game_engine::unity::scene_manager::SceneManager;
 use asr::Process;
+use attach_status::{AttachHealth, AttachStatus, CrashRecord};
+use branches::Branches;
+use death_stats::DeathStats;
+use deferred::DeferredSplit;
+use diagnostics::{Diagnostics, SplitContext};
+use flower_quest::FlowerQuest;
+use game_patch::GamePatch;
+use game_state_vars::GameStateVars;
 use game_time::{GameTime, GameTimePlusVars};
-use hit_counter::{HitCounter, DASH};
+use hit_counter::{HitCounter, HitCounting, DASH};
+use hit_penalty::HitPenalty;
 use hollow_knight_memory::*;
 use load_remover::LoadRemover;
-use settings_gui::{HitsMethod, SettingsGui, TimingMethod};
+use log_level::{log, log_enabled, set_log_level};
+use next_split::NextSplit;
+use occurrence::OccurrenceCounter;
+use play_time::PlayTime;
+use resource_tracker::ResourceTracker;
+use run_snapshot::RunSnapshot;
+use settings_gui::{HitsMethod, LogLevel, ResourceTracking, SettingsGui, TimingMethod};
+use split_arming::SplitArming;
+use split_delay::SplitDelay;
+use split_entry::SplitEntry;
+use split_wrappers::{wrap_action, WrapperContext, WrapperStores};
 use splits::Split;
 use timer::{Resettable, SplitterAction, Timer};
 use ugly_widget::store::StoreGui;
@@ -46,25 +88,79 @@ const TICKS_PER_GUI: usize = 0x100;
 struct AutoSplitterState {
     timing_method: TimingMethod,
     hits_method: HitsMethod,
-    splits: Vec<Split>,
+    hit_penalty: settings_gui::HitPenalty,
+    play_time_var: bool,
+    deaths_var: bool,
+    resource_tracking: ResourceTracking,
+    splits: Vec<SplitEntry>,
+    arm_splits: bool,
+    split_arming: SplitArming,
+    occurrences: OccurrenceCounter,
+    deferred: DeferredSplit,
+    split_delay: SplitDelay,
+    branches: Branches,
     load_remover: GameTimePlusVars,
     timer: Timer,
+    game_state_vars: GameStateVars,
+    flower_quest: FlowerQuest,
+    crash_record: CrashRecord,
+    next_split: NextSplit,
+    run_snapshot: RunSnapshot,
+    diagnostics: Diagnostics,
+    game_patch: Option<GamePatch>,
+    log_scene_data: bool,
+    /// The split taking a Scene Data item, and the items' values when it started
+    taking_item: Option<(usize, PersistentItemValues)>,
 }
 
 impl AutoSplitterState {
-    fn new(
-        timing_method: TimingMethod,
-        hits_method: HitsMethod,
-        splits: Vec<Split>,
-    ) -> AutoSplitterState {
-        let load_remover = timing_method_game_time(splits.len(), timing_method, hits_method);
-        let timer = Timer::new(splits.len(), splits::auto_reset_safe(&splits));
+    fn new(gui: &SettingsGui) -> AutoSplitterState {
+        let timing_method = gui.get_timing_method();
+        let hits_method = gui.get_hit_counter();
+        let hit_penalty = gui.get_hit_penalty();
+        let play_time_var = gui.get_play_time_var();
+        let deaths_var = gui.get_deaths_var();
+        let resource_tracking = gui.get_resource_tracking();
+        let splits = gui.get_splits();
+        let game_state_vars = GameStateVars::new(gui.get_game_state_vars());
+        let flower_quest = FlowerQuest::new(gui.get_flower_var(), gui.get_flower_broken());
+        let load_remover = timing_method_game_time(
+            splits.len(),
+            timing_method,
+            hits_method,
+            hit_penalty,
+            play_time_var,
+            deaths_var,
+            resource_tracking,
+        );
+        let timer = Timer::new(splits.len(), split_entry::auto_reset_safe(&splits));
+        let branches = Branches::new(&splits);
+        let split_delay = SplitDelay::new(&splits);
         AutoSplitterState {
             timing_method,
             hits_method,
+            hit_penalty,
+            play_time_var,
+            deaths_var,
+            resource_tracking,
             splits,
+            arm_splits: false,
+            split_arming: SplitArming::new(),
+            occurrences: OccurrenceCounter::new(),
+            deferred: DeferredSplit::new(),
+            split_delay,
+            branches,
             load_remover,
             timer,
+            game_state_vars,
+            flower_quest,
+            crash_record: CrashRecord::new(),
+            next_split: NextSplit::new(),
+            run_snapshot: RunSnapshot::new(),
+            diagnostics: Diagnostics::new(),
+            game_patch: None,
+            log_scene_data: false,
+            taking_item: None,
         }
     }
 }
@@ -86,95 +182,183 @@ async fn main() {
     asr::timer::set_variable("comparison hits", DASH);
     asr::timer::set_variable("delta hits", DASH);
     asr::timer::set_variable("item", "");
+    asr::timer::set_variable("patch", "");
+    AttachStatus::NotAttached.set_variable();
 
     let mut gui = Box::new(SettingsGui::wait_load_merge_register().await);
+    set_log_level(gui.get_log_level());
 
     let mut ticks_since_gui = 0;
-    let mut state = Box::new(AutoSplitterState::new(
-        gui.get_timing_method(),
-        gui.get_hit_counter(),
-        gui.get_splits(),
-    ));
+    let mut state = Box::new(AutoSplitterState::new(&gui));
     asr::print_message(&format!("timing_method: {:?}", state.timing_method));
     asr::print_message(&format!("hit_counter: {:?}", state.hits_method));
+    asr::print_message(&format!("hit_penalty: {:?}", state.hit_penalty));
+    asr::print_message(&format!("play_time_var: {:?}", state.play_time_var));
+    asr::print_message(&format!("deaths_var: {:?}", state.deaths_var));
+    asr::print_message(&format!("resource_tracking: {:?}", state.resource_tracking));
     asr::print_message(&format!("splits: {:?}", state.splits));
+    state.arm_splits = gui.get_arm_splits();
+    asr::print_message(&format!("arm_splits: {:?}", state.arm_splits));
+    asr::print_message(&format!(
+        "game_state_vars: {:?}",
+        state.game_state_vars.enabled()
+    ));
+    asr::print_message(&format!(
+        "flower_quest: var {:?}, broken {:?}",
+        state.flower_quest.var(),
+        state.flower_quest.broken_action()
+    ));
+
+    // These survive the game closing or crashing, as if it was a quitout
+    let mut scene_store = Box::new(SceneStore::new());
+    let mut player_data_store = Box::new(PlayerDataStore::new());
+    let mut scene_data_store = Box::new(SceneDataStore::new());
+
+    run_snapshot::restore_run_snapshot(
+        &mut state.timer,
+        &mut state.load_remover,
+        &mut player_data_store,
+    );
 
     loop {
         let process = wait_attach_hollow_knight(&mut *gui, &mut state).await;
         process
             .until_closes(async {
                 // TODO: Load some initial information from the process.
-                let mut scene_store = Box::new(SceneStore::new());
-
-                next_tick().await;
-                let game_manager_finder = Box::new(GameManagerFinder::wait_attach(&process).await);
-                let mut player_data_store = Box::new(PlayerDataStore::new());
-                let mut scene_data_store = Box::new(SceneDataStore::new());
-
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!("geo: {:?}", game_manager_finder.get_geo(&process)));
-
-                #[cfg(debug_assertions)]
-                let mut scenes_grub_rescued = game_manager_finder.scenes_grub_rescued(&process);
-                #[cfg(debug_assertions)]
-                asr::print_message(&format!("scenes_grub_rescued: {:?}", scenes_grub_rescued));
-
-                next_tick().await;
-                // Initialize pointers for load-remover before timer is running
-                game_manager_finder
-                    .init_load_removal_pointers(&process)
-                    .await;
-                next_tick().await;
-                asr::print_message("Initialized load removal pointers");
-                next_tick().await;
+                loop {
+                    AttachStatus::Attaching.set_variable();
+                    // Keep game time paused until the load remover can take over again
+                    asr::timer::pause_game_time();
+                    state.load_remover.detached();
+                    state.split_delay.detached();
+                    state.occurrences.detached();
+                    state.branches.detached();
+                    scene_data_store.detached();
+                    next_tick().await;
+                    let game_manager_finder =
+                        Box::new(GameManagerFinder::wait_attach(&process).await);
 
-                let scene_manager = SceneManager::attach(&process);
-                if scene_manager.is_some() {
-                    asr::print_message("Attached SceneManager");
-                } else {
-                    asr::print_message("Warning: SceneManager not found");
-                }
-                next_tick().await;
+                    log!(Debug, "geo: {:?}", game_manager_finder.get_geo(&process));
 
-                loop {
-                    tick_action(
-                        &process,
-                        &mut state,
-                        scene_manager.as_ref(),
-                        &game_manager_finder,
-                        &mut scene_store,
-                        &mut player_data_store,
-                        &mut scene_data_store,
-                    )
-                    .await;
-
-                    state.load_remover.update_game_time(
-                        &state.timer,
-                        &process,
-                        &game_manager_finder,
-                    );
+                    let mut scenes_grub_rescued = None;
+                    if log_enabled(LogLevel::Trace) {
+                        scenes_grub_rescued = game_manager_finder.scenes_grub_rescued(&process);
+                        log!(Trace, "scenes_grub_rescued: {:?}", scenes_grub_rescued);
+                    }
 
-                    #[cfg(debug_assertions)]
-                    let new_scenes_grub_rescued = game_manager_finder.scenes_grub_rescued(&process);
-                    #[cfg(debug_assertions)]
-                    if new_scenes_grub_rescued != scenes_grub_rescued {
-                        scenes_grub_rescued = new_scenes_grub_rescued;
+                    next_tick().await;
+                    // Initialize pointers for load-remover before timer is running
+                    if let Err(path) = game_manager_finder
+                        .init_load_removal_pointers(&process)
+                        .await
+                    {
                         asr::print_message(&format!(
-                            "scenes_grub_rescued: {:?}",
-                            scenes_grub_rescued
+                            "Failed to initialize load removal pointer: {}",
+                            path
                         ));
+                        AttachStatus::PointersFailing.set_variable();
+                        continue;
                     }
+                    next_tick().await;
+                    asr::print_message("Initialized load removal pointers");
+                    next_tick().await;
 
-                    ticks_since_gui += 1;
-                    if TICKS_PER_GUI <= ticks_since_gui && gui.load_update_store_if_unchanged() {
-                        check_state_change(&mut gui, &mut state);
-                        ticks_since_gui = 0;
-                    }
+                    let game_patch = game_manager_finder
+                        .get_game_patch(&process)
+                        .unwrap_or(GamePatch::Unknown);
+                    asr::print_message(&format!(
+                        "game_version: {:?}, game_patch: {:?}",
+                        game_manager_finder.get_version_string(&process),
+                        game_patch
+                    ));
+                    asr::timer::set_variable("patch", game_patch.name());
+                    game_patch.warn_unreliable_splits(state.splits.iter().map(|e| &e.split));
+                    state.game_patch = Some(game_patch);
+                    next_tick().await;
 
+                    let scene_manager = SceneManager::attach(&process);
+                    if scene_manager.is_some() {
+                        asr::print_message("Attached SceneManager");
+                    } else {
+                        asr::print_message("Warning: SceneManager not found");
+                    }
+                    AttachStatus::Attached.set_variable();
                     next_tick().await;
+
+                    let mut attach_health = AttachHealth::new();
+                    loop {
+                        tick_action(
+                            &process,
+                            &mut state,
+                            scene_manager.as_ref(),
+                            &game_manager_finder,
+                            &mut scene_store,
+                            &mut player_data_store,
+                            &mut scene_data_store,
+                        )
+                        .await;
+
+                        state.load_remover.update_game_time(
+                            &state.timer,
+                            &process,
+                            &game_manager_finder,
+                        );
+                        state.game_state_vars.update(&process, &game_manager_finder);
+                        state.crash_record.update(&state.timer);
+                        state.next_split.update(&state.timer, &state.splits);
+                        state.run_snapshot.update(
+                            &state.timer,
+                            &state.load_remover,
+                            &player_data_store,
+                        );
+
+                        if log_enabled(LogLevel::Trace) {
+                            let new_scenes_grub_rescued =
+                                game_manager_finder.scenes_grub_rescued(&process);
+                            if new_scenes_grub_rescued != scenes_grub_rescued {
+                                scenes_grub_rescued = new_scenes_grub_rescued;
+                                log!(Trace, "scenes_grub_rescued: {:?}", scenes_grub_rescued);
+                            }
+                        }
+
+                        ticks_since_gui += 1;
+                        if TICKS_PER_GUI <= ticks_since_gui && gui.load_update_store_if_unchanged()
+                        {
+                            check_state_change(&mut gui, &mut state);
+                            ticks_since_gui = 0;
+                        }
+
+                        if ticks_since_gui == 0 {
+                            take_scene_data_item(
+                                &mut gui,
+                                &mut state,
+                                &process,
+                                &game_manager_finder,
+                            );
+                        }
+
+                        let log_scene_data = gui.get_log_scene_data();
+                        if log_scene_data && !state.log_scene_data {
+                            scene_data_store.log_persistent_items(&process, &game_manager_finder);
+                        }
+                        state.log_scene_data = log_scene_data;
+                        state.diagnostics.set_enabled(gui.get_split_diagnostics());
+
+                        if attach_health.update(&process, &game_manager_finder) {
+                            asr::print_message(&format!(
+                                "Pointers still failing, detecting module and image again: {:?}",
+                                attach_health.failing()
+                            ));
+                            break;
+                        }
+
+                        next_tick().await;
+                    }
                 }
             })
             .await;
+        state.crash_record.process_lost(&state.timer);
+        scene_store.process_lost();
     }
 }
 
@@ -182,38 +366,104 @@ async fn wait_attach_hollow_knight(
     gui: &mut SettingsGui,
     state: &mut AutoSplitterState,
 ) -> Process {
+    AttachStatus::NotAttached.set_variable();
     retry(|| {
         gui.loop_load_update_store();
         state.timer.update(&mut state.load_remover);
+        // The load remover isn't running, so keep game time paused
+        asr::timer::pause_game_time();
+        state.crash_record.update(&state.timer);
         check_state_change(gui, state);
+        state.next_split.update(&state.timer, &state.splits);
         attach_hollow_knight()
     })
     .await
 }
 
 fn check_state_change(gui: &mut SettingsGui, state: &mut AutoSplitterState) {
+    set_log_level(gui.get_log_level());
+    state.arm_splits = gui.get_arm_splits();
     if state.timer.is_timer_state_between_runs() {
         match (
             gui.check_timing_method(&mut state.timing_method),
             gui.check_hit_counter(&mut state.hits_method),
+            gui.check_hit_penalty(&mut state.hit_penalty),
+            gui.check_play_time_var(&mut state.play_time_var),
+            gui.check_deaths_var(&mut state.deaths_var),
+            gui.check_resource_tracking(&mut state.resource_tracking),
         ) {
-            (None, None) => (),
-            _ => {
+            (None, None, None, None, None, None) => (),
+            (_, _, _, play_time_var, deaths_var, _) => {
+                if play_time_var == Some(false) && state.timing_method != TimingMethod::PlayTime {
+                    asr::timer::set_variable("play time", "");
+                }
+                if deaths_var == Some(false) {
+                    death_stats::clear_variables();
+                }
                 state.load_remover = timing_method_game_time(
                     state.timer.n(),
                     state.timing_method,
                     state.hits_method,
+                    state.hit_penalty,
+                    state.play_time_var,
+                    state.deaths_var,
+                    state.resource_tracking,
                 );
             }
         }
     }
+    gui.check_game_state_vars(&mut state.game_state_vars);
+    gui.check_flower_quest(&mut state.flower_quest);
     if let Some(new_splits) = gui.check_splits(&mut state.splits) {
         state
             .timer
-            .renew(new_splits.len(), splits::auto_reset_safe(new_splits));
+            .renew(new_splits.len(), split_entry::auto_reset_safe(new_splits));
+        state.branches = Branches::new(new_splits);
+        state.split_delay = SplitDelay::new(new_splits);
+        if let Some(game_patch) = state.game_patch {
+            game_patch.warn_unreliable_splits(new_splits.iter().map(|e| &e.split));
+        }
     }
 }
 
+/// Fills in the scene, id, and value of a split with Take Scene Data Item checked,
+/// from the first Scene Data item that changes after it was checked.
+fn take_scene_data_item(
+    gui: &mut SettingsGui,
+    state: &mut AutoSplitterState,
+    process: &Process,
+    game_manager_finder: &GameManagerFinder,
+) {
+    let Some(i) = state.splits.iter().position(|e| e.take_item) else {
+        state.taking_item = None;
+        return;
+    };
+    let ints = state.splits[i].split == Split::PersistentIntItem;
+    let Some(values) = PersistentItemValues::read(process, game_manager_finder, ints) else {
+        return;
+    };
+    match &state.taking_item {
+        Some((j, old)) if *j == i => {
+            if let Some((scene, id, value)) = values.changed_since(old) {
+                asr::print_message(&format!(
+                    "Took Scene Data item ({:?}, {:?}): {} for split {}",
+                    scene, id, value, i
+                ));
+                gui.take_scene_data_item(i, scene, id, ints.then_some(value));
+                state.taking_item = None;
+                return;
+            }
+        }
+        _ => {
+            asr::print_message(&format!(
+                "Taking the next Scene Data item that changes for split {}",
+                i
+            ));
+        }
+    }
+    state.taking_item = Some((i, values));
+}
+
 async fn tick_action(
     process: &Process,
     state: &mut AutoSplitterState,
@@ -225,22 +475,109 @@ async fn tick_action(
 ) {
     state.timer.update(&mut state.load_remover);
 
-    let trans_now = scene_store.transition_now(&process, scene_manager, &game_manager_finder);
+    let a = state
+        .split_delay
+        .update(&state.timer, process, game_manager_finder);
+    if a == SplitterAction::Split {
+        state.branches.record_split(state.timer.i());
+        state.timer.action(a, &mut state.load_remover);
+        next_tick().await;
+        asr::timer::set_variable("item", "");
+    }
+
+    let trans_now = scene_store.transition_now(process, scene_manager, game_manager_finder);
+    loop {
+        let a =
+            state
+                .flower_quest
+                .action(process, game_manager_finder, &state.timer, &state.splits);
+        if a == SplitterAction::Pass {
+            break;
+        }
+        state.timer.action(a, &mut state.load_remover);
+        next_tick().await;
+    }
+    state.occurrences.update(&state.timer, &state.splits);
+    state.branches.update(&state.timer);
     loop {
+        if state.branches.should_skip(&state.timer) {
+            state
+                .timer
+                .action(SplitterAction::Skip, &mut state.load_remover);
+            next_tick().await;
+            continue;
+        }
         let Some(s) = state.splits.get(state.timer.i()) else {
             break;
         };
-        let a = splits::splits(
-            s,
-            &process,
+        if state.split_delay.is_waiting(state.timer.i()) {
+            break;
+        }
+        let mut context = state
+            .diagnostics
+            .enabled()
+            .then(|| SplitContext::read(process, game_manager_finder, trans_now, scene_store));
+        if context.is_some() {
+            game_manager_finder.start_recording_reads();
+        }
+        let e = s.evaluate(
+            process,
             game_manager_finder,
             trans_now,
             scene_store,
             player_data_store,
             scene_data_store,
         );
+        if let Some(context) = context.as_mut() {
+            context.reads = game_manager_finder.take_recorded_reads();
+        }
+        let i = state.timer.i();
+        let cx = WrapperContext {
+            arm_splits: state.arm_splits,
+            game_state: game_manager_finder.get_game_state(process),
+            trans_now,
+        };
+        let stores = WrapperStores {
+            occurrences: &mut state.occurrences,
+            arming: &mut state.split_arming,
+            deferred: &mut state.deferred,
+            delay: &mut state.split_delay,
+            branches: &mut state.branches,
+        };
+        let wrapped = wrap_action(
+            cx,
+            stores,
+            &state.splits,
+            i,
+            e.action(),
+            scene_store,
+            |e, ss, stores| {
+                e.action(
+                    process,
+                    game_manager_finder,
+                    trans_now,
+                    ss,
+                    &mut stores.pds,
+                    &mut stores.sds,
+                )
+            },
+        );
+        if let Some(j) = wrapped.branch {
+            // skip the alternatives before the one taken, up to its first split
+            while state.timer.i() < j {
+                state
+                    .timer
+                    .action(SplitterAction::Skip, &mut state.load_remover);
+                next_tick().await;
+            }
+        }
+        let a = wrapped.action.clone();
+        if let Some(context) = context {
+            state.diagnostics.record(i, s, context, e, wrapped);
+        }
         match a {
             SplitterAction::Split | SplitterAction::ManualSplit => {
+                state.branches.record_split(state.timer.i());
                 state.timer.action(a, &mut state.load_remover);
                 next_tick().await;
                 asr::timer::set_variable("item", "");
@@ -253,9 +590,8 @@ async fn tick_action(
             }
             SplitterAction::Pass => {
                 if state.timer.is_auto_reset_safe() {
-                    let a0 = splits::splits(
-                        &state.splits[0],
-                        &process,
+                    let a0 = state.splits[0].action(
+                        process,
                         game_manager_finder,
                         trans_now,
                         scene_store,
@@ -275,13 +611,32 @@ async fn tick_action(
         }
     }
 
+    let split_this_transition = scene_store.split_this_transition;
+    state.occurrences.count_ahead(
+        &state.timer,
+        &state.splits,
+        // the alternatives of a branch are counted when they're evaluated
+        |j| state.branches.is_racing(j),
+        |e, stores| {
+            // Splits ahead neither see nor set whether the current split split this transition
+            scene_store.split_this_transition = false;
+            e.action(
+                process,
+                game_manager_finder,
+                trans_now,
+                scene_store,
+                &mut stores.pds,
+                &mut stores.sds,
+            )
+        },
+    );
+    scene_store.split_this_transition = split_this_transition;
+
     if trans_now {
-        if scene_store.pair().old == MENU_TITLE {
-            player_data_store.reset();
-            scene_data_store.reset();
-        } else {
-            player_data_store.clean_on_entry();
-        }
+        let old_scene = scene_store.pair().old;
+        split_entry::forget_on_transition(old_scene, player_data_store, scene_data_store);
+        state.occurrences.on_transition(old_scene);
+        state.branches.on_transition(old_scene);
     }
 }
 
@@ -289,16 +644,60 @@ fn timing_method_game_time(
     n: usize,
     timing_method: TimingMethod,
     hits_method: HitsMethod,
+    hit_penalty: settings_gui::HitPenalty,
+    play_time_var: bool,
+    deaths_var: bool,
+    resource_tracking: ResourceTracking,
 ) -> GameTimePlusVars {
-    match timing_method {
+    let game_time = match timing_method {
         TimingMethod::LoadRemovedTime => match hits_method {
             HitsMethod::None => GameTimePlusVars::new(Box::new(LoadRemover::new())),
             HitsMethod::HitsDreamFalls => GameTimePlusVars::new(Box::new(LoadRemover::new()))
-                .with_var(Box::new(HitCounter::new(n, true))),
+                .with_var(Box::new(HitCounter::new(n, HitCounting::DreamFalls))),
             HitsMethod::HitsDamage => GameTimePlusVars::new(Box::new(LoadRemover::new()))
-                .with_var(Box::new(HitCounter::new(n, false))),
+                .with_var(Box::new(HitCounter::new(n, HitCounting::Damage))),
+            HitsMethod::HitsMasksLost => GameTimePlusVars::new(Box::new(LoadRemover::new()))
+                .with_var(Box::new(HitCounter::new(n, HitCounting::MasksLost))),
         },
-        TimingMethod::HitsDreamFalls => GameTimePlusVars::new(Box::new(HitCounter::new(n, true))),
-        TimingMethod::HitsDamage => GameTimePlusVars::new(Box::new(HitCounter::new(n, false))),
+        TimingMethod::HitsDreamFalls => {
+            GameTimePlusVars::new(Box::new(HitCounter::new(n, HitCounting::DreamFalls)))
+        }
+        TimingMethod::HitsDamage => {
+            GameTimePlusVars::new(Box::new(HitCounter::new(n, HitCounting::Damage)))
+        }
+        TimingMethod::HitsMasksLost => {
+            GameTimePlusVars::new(Box::new(HitCounter::new(n, HitCounting::MasksLost)))
+        }
+        TimingMethod::PlayTime => GameTimePlusVars::new(Box::new(PlayTime::new())),
+        TimingMethod::HitPenalty => {
+            let counting = match hits_method {
+                HitsMethod::HitsDreamFalls => HitCounting::DreamFalls,
+                HitsMethod::None | HitsMethod::HitsDamage => HitCounting::Damage,
+                HitsMethod::HitsMasksLost => HitCounting::MasksLost,
+            };
+            GameTimePlusVars::new(Box::new(HitPenalty::new(
+                LoadRemover::new(),
+                HitCounter::new(n, counting),
+                hit_penalty.seconds(),
+            )))
+        }
+    };
+    // PlayTime as the timing method already sets the play time variable
+    let game_time = if play_time_var && timing_method != TimingMethod::PlayTime {
+        game_time.with_var(Box::new(PlayTime::new()))
+    } else {
+        game_time
+    };
+    let game_time = if deaths_var {
+        game_time.with_var(Box::new(DeathStats::new()))
+    } else {
+        game_time
+    };
+    match resource_tracking {
+        ResourceTracking::None => game_time,
+        ResourceTracking::Variables => game_time.with_var(Box::new(ResourceTracker::new(n, false))),
+        ResourceTracking::VariablesAndBests => {
+            game_time.with_var(Box::new(ResourceTracker::new(n, true)))
+        }
     }
 }
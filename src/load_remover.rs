@@ -1,17 +1,14 @@
-#[cfg(debug_assertions)]
-use alloc::format;
-
 use asr::timer::TimerState;
 use asr::Process;
 
 use crate::game_time::GameTime;
 use crate::hollow_knight_memory::*;
+use crate::log_level::log;
 use crate::timer::{Resettable, Timer};
 
 pub struct LoadRemover {
     look_for_teleporting: bool,
     last_game_state: i32,
-    #[cfg(debug_assertions)]
     last_paused: bool,
 }
 
@@ -20,10 +17,7 @@ impl Resettable for LoadRemover {
     fn reset(&mut self) {
         self.look_for_teleporting = false;
         self.last_game_state = GAME_STATE_INACTIVE;
-        #[cfg(debug_assertions)]
-        {
-            self.last_paused = false;
-        }
+        self.last_paused = false;
     }
 }
 
@@ -33,7 +27,6 @@ impl LoadRemover {
         LoadRemover {
             look_for_teleporting: false,
             last_game_state: GAME_STATE_INACTIVE,
-            #[cfg(debug_assertions)]
             last_paused: false,
         }
     }
@@ -48,38 +41,52 @@ impl GameTime for LoadRemover {
         process: &Process,
         game_manager_finder: &GameManagerFinder,
     ) {
+        if self.is_game_time_paused(process, game_manager_finder) == Some(false) {
+            asr::timer::resume_game_time();
+        } else {
+            asr::timer::pause_game_time();
+        }
+    }
+}
+
+impl LoadRemover {
+    /// Whether game time should be paused for a load,
+    /// or None if the timer isn't running.
+    pub fn is_game_time_paused(
+        &mut self,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) -> Option<bool> {
         // Initialize pointers for load-remover before timer is running
         let maybe_ui_state = game_manager_finder.get_ui_state(process);
-        let maybe_scene_name = game_manager_finder.get_scene_name(process);
-        let maybe_next_scene = game_manager_finder.get_next_scene_name(process);
+        let maybe_scene_name = game_manager_finder.get_scene_name_buf(process);
+        let maybe_next_scene = game_manager_finder.get_next_scene_name_buf(process);
         let maybe_teleporting = game_manager_finder.camera_teleporting(process);
         let maybe_game_state = game_manager_finder.get_game_state(process);
         let maybe_hazard_respawning = game_manager_finder.hazard_respawning(process);
         let maybe_accepting_input = game_manager_finder.accepting_input(process);
         let maybe_hero_transition_state = game_manager_finder.hero_transition_state(process);
         let maybe_tile_map_dirty = game_manager_finder.tile_map_dirty(process);
-        let maybe_uses_scene_transition_routine =
-            game_manager_finder.uses_scene_transition_routine(process);
+        let maybe_patch_behavior = game_manager_finder
+            .get_game_patch(process)
+            .map(|patch| patch.behavior());
 
         // only remove loads if timer is running
         if asr::timer::state() != TimerState::Running {
-            asr::timer::pause_game_time();
-            return;
+            return None;
         }
 
         let ui_state = maybe_ui_state.unwrap_or_default();
 
-        let scene_name = maybe_scene_name.clone().unwrap_or_default();
-        fn is_none_or_empty(ms: Option<&str>) -> bool {
-            match ms {
-                None | Some("") => true,
-                Some(_) => false,
-            }
+        let scene_name = maybe_scene_name.unwrap_or_default();
+        fn is_none_or_empty(ms: Option<&SceneName>) -> bool {
+            ms.is_none_or(|s| s.is_empty())
         }
-        let loading_menu = (scene_name != "Menu_Title"
-            && is_none_or_empty(maybe_next_scene.as_deref()))
-            || (scene_name != "Menu_Title" && maybe_next_scene.as_deref() == Some("Menu_Title")
-                || (scene_name == "Quit_To_Menu"));
+        let loading_menu = (!scene_name.is("Menu_Title")
+            && is_none_or_empty(maybe_next_scene.as_ref()))
+            || (!scene_name.is("Menu_Title")
+                && maybe_next_scene.is_some_and(|n| n.is("Menu_Title"))
+                || (scene_name.is("Quit_To_Menu")));
 
         let teleporting = maybe_teleporting.unwrap_or_default();
 
@@ -99,7 +106,8 @@ impl GameTime for LoadRemover {
         let accepting_input = maybe_accepting_input.unwrap_or_default();
         let hero_transition_state = maybe_hero_transition_state.unwrap_or_default();
         let tile_map_dirty = maybe_tile_map_dirty.unwrap_or_default();
-        let uses_scene_transition_routine = maybe_uses_scene_transition_routine.unwrap_or_default();
+        let uses_scene_transition_routine =
+            maybe_patch_behavior.is_some_and(|b| b.uses_scene_transition_routine);
         let is_game_time_paused =
             (game_state == GAME_STATE_PLAYING && teleporting && !hazard_respawning)
                 || (self.look_for_teleporting)
@@ -111,23 +119,16 @@ impl GameTime for LoadRemover {
                 || (ui_state != UI_STATE_PLAYING
                     && (loading_menu
                         || (ui_state != UI_STATE_PAUSED
-                            && (!is_none_or_empty(maybe_next_scene.as_deref())
-                                || scene_name == "_test_charms")))
+                            && (!is_none_or_empty(maybe_next_scene.as_ref())
+                                || scene_name.is("_test_charms"))))
                     && maybe_next_scene != Some(scene_name))
                 || (tile_map_dirty && !uses_scene_transition_routine);
-        if is_game_time_paused {
-            asr::timer::pause_game_time();
-        } else {
-            asr::timer::resume_game_time();
-        }
 
         self.last_game_state = game_state;
-        #[cfg(debug_assertions)]
-        {
-            if is_game_time_paused != self.last_paused {
-                asr::print_message(&format!("is_game_time_paused: {}", is_game_time_paused));
-            }
-            self.last_paused = is_game_time_paused;
+        if is_game_time_paused != self.last_paused {
+            log!(Info, "is_game_time_paused: {}", is_game_time_paused);
         }
+        self.last_paused = is_game_time_paused;
+        Some(is_game_time_paused)
     }
 }
@@ -0,0 +1,36 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+use crate::settings_gui::LogLevel;
+
+/// The log level from the settings, kept globally so that every store can check it
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Off as u8);
+
+pub fn set_log_level(level: LogLevel) {
+    LOG_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+/// Whether messages at this level should be logged
+pub fn log_enabled(level: LogLevel) -> bool {
+    level != LogLevel::Off && level as u8 <= LOG_LEVEL.load(Ordering::Relaxed)
+}
+
+/// Prints to the auto splitter log, or to stderr when running natively,
+/// such as in the tick path benchmark.
+pub fn print_log(message: &str) {
+    #[cfg(target_family = "wasm")]
+    asr::print_message(message);
+    #[cfg(not(target_family = "wasm"))]
+    std::eprintln!("{}", message);
+}
+
+/// Prints a message if the log level allows it,
+/// only formatting the message when it will be printed.
+macro_rules! log {
+    ($level:ident, $($arg:tt)*) => {
+        if $crate::log_level::log_enabled($crate::settings_gui::LogLevel::$level) {
+            $crate::log_level::print_log(&::alloc::format!($($arg)*));
+        }
+    };
+}
+
+pub(crate) use log;
@@ -0,0 +1,38 @@
+use ugly_widget::radio_button::RadioButtonOptions;
+
+use crate::split_entry::SplitEntry;
+use crate::splits::Split;
+use crate::timer::Timer;
+
+/// Publishes the description and tooltip of the split the autosplitter is waiting for,
+/// as the `next split` and `next split hint` custom variables.
+pub struct NextSplit {
+    /// The index and split last published, or None before the first update
+    last: Option<Option<(usize, Split)>>,
+}
+
+impl NextSplit {
+    pub fn new() -> NextSplit {
+        NextSplit { last: None }
+    }
+
+    /// Updates the variables whenever the timer's index or the split at it changes,
+    /// including manual skips and undos the timer picked up.
+    pub fn update(&mut self, timer: &Timer, splits: &[SplitEntry]) {
+        let i = timer.i();
+        let current = splits.get(i).map(|e| (i, e.split.clone()));
+        if self.last.as_ref() == Some(&current) {
+            return;
+        }
+        let option = current.as_ref().and_then(|(_, split)| {
+            Split::radio_button_options()
+                .into_iter()
+                .find(|o| &o.value == split)
+        });
+        let (description, hint) =
+            option.map_or(("", ""), |o| (o.description, o.tooltip.unwrap_or("")));
+        asr::timer::set_variable("next split", description);
+        asr::timer::set_variable("next split hint", hint);
+        self.last = Some(current);
+    }
+}
@@ -0,0 +1,381 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use asr::settings::gui::Gui;
+use ugly_widget::{
+    radio_button::{options_str, RadioButtonOptions},
+    store::StoreWidget,
+};
+
+use crate::split_entry::{SplitEntry, SplitStores};
+use crate::timer::{SplitterAction, Timer};
+
+/// Which occurrence of a split's condition to split on
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum Occurrence {
+    /// 1st
+    #[default]
+    First,
+    /// 2nd
+    Second,
+    /// 3rd
+    Third,
+    /// 4th
+    Fourth,
+    /// 5th
+    Fifth,
+    /// 6th
+    Sixth,
+    /// 7th
+    Seventh,
+    /// 8th
+    Eighth,
+    /// 9th
+    Ninth,
+    /// 10th
+    Tenth,
+}
+
+impl StoreWidget for Occurrence {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+impl Occurrence {
+    pub fn n(&self) -> u32 {
+        *self as u32 + 1
+    }
+}
+
+/// Where to start counting occurrences from
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum CountFrom {
+    /// Previous split
+    ///
+    /// Counts occurrences since the split before it
+    #[default]
+    PreviousSplit,
+    /// Run start
+    ///
+    /// Counts occurrences since the run started,
+    /// including ones before the split before it
+    RunStart,
+}
+
+impl StoreWidget for CountFrom {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Count {
+    n: u32,
+    /// Whether the split's condition split on the last evaluation,
+    /// so that a condition which stays true counts once
+    was_split: bool,
+}
+
+impl Count {
+    fn count(&mut self, a: &SplitterAction) {
+        let is_split = *a == SplitterAction::Split;
+        if is_split && !self.was_split {
+            self.n += 1;
+        }
+        self.was_split = is_split;
+    }
+}
+
+/// Counts how many times each split's condition would have split,
+/// for splits that only split on a later occurrence.
+pub struct OccurrenceCounter {
+    counts: Vec<Count>,
+    /// Change tracking for each split ahead that counts from the run start, by index
+    ahead: BTreeMap<usize, SplitStores>,
+    last_i: usize,
+    last_between_runs: bool,
+}
+
+impl OccurrenceCounter {
+    pub fn new() -> OccurrenceCounter {
+        OccurrenceCounter {
+            counts: Vec::new(),
+            ahead: BTreeMap::new(),
+            last_i: 0,
+            last_between_runs: true,
+        }
+    }
+
+    /// Clears the counts when the run resets,
+    /// and the counts since the previous split when the timer moves to another split
+    pub fn update(&mut self, timer: &Timer, splits: &[SplitEntry]) {
+        self.update_at(timer.i(), timer.is_timer_state_between_runs(), splits);
+    }
+
+    fn update_at(&mut self, i: usize, between_runs: bool, splits: &[SplitEntry]) {
+        self.counts.resize(splits.len(), Count::default());
+        if between_runs && !self.last_between_runs {
+            self.counts.fill(Count::default());
+            self.ahead.clear();
+        } else if i != self.last_i {
+            for (c, e) in self.counts.iter_mut().zip(splits) {
+                if e.count_from == CountFrom::PreviousSplit {
+                    *c = Count::default();
+                }
+            }
+            // splits the timer reached use the current split's change tracking from now on
+            self.ahead.retain(|&j, _| i < j);
+        }
+        self.last_i = i;
+        self.last_between_runs = between_runs;
+    }
+
+    /// Passes instead of splitting until the occurrence the split at index `i` is waiting for.
+    ///
+    /// A split that counts from the previous split starts counting when the timer moves to it.
+    pub fn filter(&mut self, i: usize, e: &SplitEntry, a: SplitterAction) -> SplitterAction {
+        if e.occurrence == Occurrence::First {
+            return a;
+        }
+        let Some(c) = self.counts.get_mut(i) else {
+            return a;
+        };
+        c.count(&a);
+        match a {
+            SplitterAction::Split if c.n < e.occurrence.n() => SplitterAction::Pass,
+            a => a,
+        }
+    }
+
+    /// Counts occurrences for the splits after the current one that count from the run start,
+    /// except for the ones at indices where `skip` is true,
+    /// evaluating each of them with `f` and its own change tracking,
+    /// so that they neither use up nor miss the changes the current split sees.
+    pub fn count_ahead<
+        S: Fn(usize) -> bool,
+        F: FnMut(&SplitEntry, &mut SplitStores) -> SplitterAction,
+    >(
+        &mut self,
+        timer: &Timer,
+        splits: &[SplitEntry],
+        skip: S,
+        f: F,
+    ) {
+        if timer.is_timer_state_between_runs() {
+            return;
+        }
+        self.count_ahead_of(timer.i(), splits, skip, f);
+    }
+
+    fn count_ahead_of<
+        S: Fn(usize) -> bool,
+        F: FnMut(&SplitEntry, &mut SplitStores) -> SplitterAction,
+    >(
+        &mut self,
+        i: usize,
+        splits: &[SplitEntry],
+        skip: S,
+        mut f: F,
+    ) {
+        for (j, e) in splits.iter().enumerate().skip(i + 1) {
+            if e.occurrence == Occurrence::First || e.count_from != CountFrom::RunStart || skip(j) {
+                continue;
+            }
+            let stores = self.ahead.entry(j).or_insert_with(SplitStores::new);
+            let a = f(e, stores);
+            self.counts[j].count(&a);
+        }
+    }
+
+    /// Forgets changes in the splits ahead's change tracking on a transition from `old_scene`
+    pub fn on_transition(&mut self, old_scene: &str) {
+        for stores in self.ahead.values_mut() {
+            stores.on_transition(old_scene);
+        }
+    }
+
+    /// Forgets addresses in the old process, when the game process is lost or attached again
+    pub fn detached(&mut self) {
+        for stores in self.ahead.values_mut() {
+            stores.detached();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASS: SplitterAction = SplitterAction::Pass;
+    const SPLIT: SplitterAction = SplitterAction::Split;
+
+    fn entry(occurrence: Occurrence, count_from: CountFrom) -> SplitEntry {
+        SplitEntry {
+            occurrence,
+            count_from,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn a_condition_that_stays_true_counts_once() {
+        let mut c = Count::default();
+        for a in [SPLIT, SPLIT, PASS, SPLIT, SplitterAction::Skip, SPLIT] {
+            c.count(&a);
+        }
+        assert_eq!(c.n, 3);
+        assert!(c.was_split);
+    }
+
+    #[test]
+    fn splits_on_the_occurrence_it_waits_for() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Third, CountFrom::PreviousSplit),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        let e = &splits[1];
+        let actions = [SPLIT, SPLIT, PASS, SPLIT, PASS, SPLIT];
+        let filtered = actions.map(|a| oc.filter(1, e, a));
+        assert_eq!(filtered, [PASS, PASS, PASS, PASS, PASS, SPLIT]);
+        // other actions go through
+        assert_eq!(
+            oc.filter(1, e, SplitterAction::Reset),
+            SplitterAction::Reset
+        );
+    }
+
+    #[test]
+    fn counting_from_the_previous_split_restarts_when_the_timer_moves() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Second, CountFrom::PreviousSplit),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        oc.update_at(2, false, &splits);
+        assert_eq!(oc.filter(2, &splits[2], SPLIT), PASS);
+        // an undo and a split back
+        oc.update_at(1, false, &splits);
+        oc.update_at(2, false, &splits);
+        assert_eq!(oc.filter(2, &splits[2], PASS), PASS);
+        assert_eq!(oc.filter(2, &splits[2], SPLIT), PASS);
+        assert_eq!(oc.filter(2, &splits[2], PASS), PASS);
+        assert_eq!(oc.filter(2, &splits[2], SPLIT), SPLIT);
+    }
+
+    #[test]
+    fn counting_from_the_run_start_counts_ahead() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Second, CountFrom::RunStart),
+            entry(Occurrence::Second, CountFrom::PreviousSplit),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        let mut evaluated = Vec::new();
+        oc.count_ahead_of(
+            1,
+            &splits,
+            |_| false,
+            |e, _| {
+                evaluated.push(e.count_from);
+                SPLIT
+            },
+        );
+        // only the split that counts from the run start is evaluated ahead
+        assert_eq!(evaluated, [CountFrom::RunStart]);
+        oc.update_at(2, false, &splits);
+        assert_eq!(oc.filter(2, &splits[2], PASS), PASS);
+        assert_eq!(oc.filter(2, &splits[2], SPLIT), SPLIT);
+        assert!(oc.ahead.is_empty());
+    }
+
+    #[test]
+    fn splits_counted_elsewhere_are_not_counted_ahead() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Second, CountFrom::RunStart),
+            entry(Occurrence::Second, CountFrom::RunStart),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        oc.count_ahead_of(1, &splits, |j| j == 2, |_, _| SPLIT);
+        assert_eq!(oc.counts[2].n, 0);
+        assert_eq!(oc.counts[3].n, 1);
+        assert!(!oc.ahead.contains_key(&2));
+    }
+
+    #[test]
+    fn splits_ahead_keep_their_own_change_tracking() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Second, CountFrom::RunStart),
+            entry(Occurrence::Third, CountFrom::RunStart),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        let mut stores = Vec::new();
+        oc.count_ahead_of(
+            1,
+            &splits,
+            |_| false,
+            |_, st| {
+                stores.push(st as *const SplitStores);
+                PASS
+            },
+        );
+        oc.count_ahead_of(
+            1,
+            &splits,
+            |_| false,
+            |_, st| {
+                stores.push(st as *const SplitStores);
+                PASS
+            },
+        );
+        assert_eq!(stores.len(), 4);
+        assert_ne!(stores[0], stores[1]);
+        assert_eq!(stores[0..2], stores[2..4]);
+        assert_eq!(oc.ahead.len(), 2);
+        oc.update_at(3, false, &splits);
+        assert_eq!(oc.ahead.len(), 0);
+    }
+
+    #[test]
+    fn a_reset_clears_the_counts() {
+        let splits = [
+            entry(Occurrence::First, CountFrom::PreviousSplit),
+            entry(Occurrence::Second, CountFrom::RunStart),
+        ];
+        let mut oc = OccurrenceCounter::new();
+        oc.update_at(1, false, &splits);
+        assert_eq!(oc.filter(1, &splits[1], SPLIT), PASS);
+        oc.update_at(0, true, &splits);
+        oc.update_at(1, false, &splits);
+        assert_eq!(oc.filter(1, &splits[1], PASS), PASS);
+        assert_eq!(oc.filter(1, &splits[1], SPLIT), PASS);
+    }
+}
@@ -0,0 +1,122 @@
+use alloc::format;
+use alloc::string::String;
+
+use asr::time::Duration;
+use asr::timer::TimerState;
+use asr::Process;
+
+use crate::game_time::GameTime;
+use crate::hollow_knight_memory::*;
+use crate::timer::{Resettable, Timer};
+
+/// The largest increase in playTime from one tick to the next that still counts as play.
+/// Anything bigger is a save being loaded, not time passing.
+const MAX_PLAY_TIME_DELTA: f32 = 1.0;
+
+/// Game time from the save file's own `playTime`, relative to its value at the start of the run.
+///
+/// Quitting out and loading a save can make `playTime` jump backwards or forwards,
+/// so only small increases from one tick to the next are added to the run's play time.
+pub struct PlayTime {
+    /// Play time in seconds since the start of the run
+    run_play_time: f64,
+    /// The last observed playTime, or None at the start of a run
+    last_play_time: Option<f32>,
+    /// The last published play time variable, in hundredths of a second
+    last_published: Option<i64>,
+}
+
+impl Resettable for PlayTime {
+    fn ended(&mut self) {}
+    fn reset(&mut self) {
+        self.run_play_time = 0.0;
+        self.last_play_time = None;
+        self.publish();
+    }
+}
+
+impl PlayTime {
+    pub fn new() -> PlayTime {
+        let mut play_time = PlayTime {
+            run_play_time: 0.0,
+            last_play_time: None,
+            last_published: None,
+        };
+        play_time.publish();
+        play_time
+    }
+
+    fn publish(&mut self) {
+        let centis = (self.run_play_time * 100.0) as i64;
+        if self.last_published != Some(centis) {
+            asr::timer::set_variable("play time", &format_centis(centis));
+            self.last_published = Some(centis);
+        }
+    }
+}
+
+impl GameTime for PlayTime {
+    /// Sets the play time variable, but does not set game time
+    fn update_variables(
+        &mut self,
+        _: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        // only count play time if timer is running
+        if asr::timer::state() != TimerState::Running {
+            return;
+        }
+        let Some(play_time) = game_manager_finder.get_play_time(process) else {
+            return;
+        };
+        if let Some(last) = self.last_play_time {
+            let delta = play_time - last;
+            if 0.0 < delta && delta <= MAX_PLAY_TIME_DELTA {
+                self.run_play_time += delta as f64;
+            } else if delta != 0.0 {
+                asr::print_message(&format!("play time jumped from {} to {}", last, play_time));
+            }
+        }
+        self.last_play_time = Some(play_time);
+        self.publish();
+    }
+
+    /// Sets game time to play time since the start of the run
+    fn update_game_time(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        asr::timer::pause_game_time();
+
+        self.update_variables(timer, process, game_manager_finder);
+
+        asr::timer::set_game_time(Duration::seconds_f64(self.run_play_time));
+    }
+
+    fn snapshot_into(&self, snapshot: &asr::settings::Map) {
+        snapshot.insert("play_time", self.run_play_time);
+    }
+
+    fn restore_from(&mut self, _: &Timer, snapshot: &asr::settings::Map) {
+        if let Some(run_play_time) = snapshot.get("play_time").and_then(|v| v.get_f64()) {
+            self.run_play_time = run_play_time;
+            self.last_play_time = None;
+            self.publish();
+        }
+    }
+}
+
+fn format_centis(centis: i64) -> String {
+    let hours = centis / 360000;
+    let minutes = (centis / 6000) % 60;
+    let seconds = (centis / 100) % 60;
+    let hundredths = centis % 100;
+    if 0 < hours {
+        format!("{}:{:02}:{:02}.{:02}", hours, minutes, seconds, hundredths)
+    } else {
+        format!("{}:{:02}.{:02}", minutes, seconds, hundredths)
+    }
+}
@@ -0,0 +1,216 @@
+use asr::settings::Gui;
+use ugly_widget::radio_button::{options_str, RadioButtonOptions};
+use ugly_widget::store::StoreWidget;
+
+use crate::auto_splitter_settings::{this_script_name, SETTINGS_VERSION};
+use crate::splits::Split;
+
+/// Named split lists for standard categories,
+/// as a starting point for runners who don't have a splits file yet.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum SplitsPreset {
+    /// None
+    #[default]
+    None,
+    /// Any%
+    AnyPercent,
+    /// All Skills
+    AllSkills,
+    /// 112% APB
+    TrueCompletionAPB,
+    /// Pantheon of Hallownest
+    PantheonOfHallownest,
+    /// Low%
+    LowPercent,
+}
+
+impl StoreWidget for SplitsPreset {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+impl SplitsPreset {
+    /// The splits for the preset, including the start and end splits,
+    /// or None for `SplitsPreset::None`.
+    pub fn splits(&self) -> Option<&'static [Split]> {
+        match self {
+            SplitsPreset::None => None,
+            SplitsPreset::AnyPercent => Some(&[
+                Split::StartNewGame,
+                Split::KingsPass,
+                Split::VengefulSpirit,
+                Split::Greenpath,
+                Split::Hornet1,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CityGateOpen,
+                Split::SoulMaster,
+                Split::Lurien,
+                Split::Uumuu,
+                Split::Monomon,
+                Split::CrystalHeart,
+                Split::Hegemol,
+                Split::EnterHollowKnight,
+                Split::EndingSplit,
+            ]),
+            SplitsPreset::AllSkills => Some(&[
+                Split::StartNewGame,
+                Split::VengefulSpirit,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CycloneSlash,
+                Split::DesolateDive,
+                Split::CrystalHeart,
+                Split::ShadeSoul,
+                Split::HowlingWraiths,
+                Split::DreamNail,
+                Split::MonarchWings,
+                Split::IsmasTear,
+                Split::DashSlash,
+                Split::GreatSlash,
+                Split::DescendingDark,
+                Split::ShadeCloak,
+                Split::AbyssShriek,
+            ]),
+            SplitsPreset::TrueCompletionAPB => Some(&[
+                Split::StartNewGame,
+                Split::FalseKnight,
+                Split::Hornet1,
+                Split::MantisLords,
+                Split::SoulMaster,
+                Split::DungDefender,
+                Split::BrokenVessel,
+                Split::BlackKnight,
+                Split::Uumuu,
+                Split::Nosk,
+                Split::Hornet2,
+                Split::TraitorLord,
+                Split::Collector,
+                Split::HiveKnight,
+                Split::ColosseumGold,
+                Split::NightmareKingGrimm,
+                Split::Dreamer3,
+                Split::Pantheon1,
+                Split::Pantheon2,
+                Split::Pantheon3,
+                Split::Pantheon4,
+                Split::Pantheon5Entry,
+                Split::RadianceP,
+            ]),
+            SplitsPreset::PantheonOfHallownest => Some(&[
+                Split::StartPantheon,
+                Split::VengeflyKingP,
+                Split::GruzMotherP,
+                Split::FalseKnightP,
+                Split::MassiveMossChargerP,
+                Split::Hornet1P,
+                Split::GorbP,
+                Split::DungDefenderP,
+                Split::SoulWarriorP,
+                Split::BroodingMawlekP,
+                Split::OroMatoNailBrosP,
+                Split::XeroP,
+                Split::CrystalGuardianP,
+                Split::SoulMasterP,
+                Split::OblobblesP,
+                Split::MantisLordsP,
+                Split::MarmuP,
+                Split::FlukemarmP,
+                Split::BrokenVesselP,
+                Split::SheoPaintmasterP,
+                Split::HiveKnightP,
+                Split::ElderHuP,
+                Split::CollectorP,
+                Split::GodTamerP,
+                Split::TroupeMasterGrimmP,
+                Split::GalienP,
+                Split::GreyPrinceZoteP,
+                Split::UumuuP,
+                Split::Hornet2P,
+                Split::SlyP,
+                Split::EnragedGuardianP,
+                Split::LostKinP,
+                Split::NoEyesP,
+                Split::TraitorLordP,
+                Split::WhiteDefenderP,
+                Split::FailedChampionP,
+                Split::MarkothP,
+                Split::WatcherKnightsP,
+                Split::SoulTyrantP,
+                Split::PureVesselP,
+                Split::NoskHornetP,
+                Split::NightmareKingGrimmP,
+                Split::RadianceP,
+            ]),
+            SplitsPreset::LowPercent => Some(&[
+                Split::StartNewGame,
+                Split::KingsPass,
+                Split::Hornet1,
+                Split::MothwingCloak,
+                Split::MantisClaw,
+                Split::CrystalHeart,
+                Split::Lurien,
+                Split::Monomon,
+                Split::Hegemol,
+                Split::EnterHollowKnight,
+                Split::EndingSplit,
+            ]),
+        }
+    }
+
+    /// A settings map in the same shape as an imported splits file,
+    /// which only includes the splits for the preset.
+    pub fn asr_settings_map(&self) -> Option<asr::settings::Map> {
+        let splits = self.splits()?;
+        let splits_list = asr::settings::List::new();
+        for split in splits {
+            splits_list.push(options_str(split));
+        }
+        let settings_map = asr::settings::Map::new();
+        settings_map.insert("script_name", this_script_name());
+        settings_map.insert("settings_version", SETTINGS_VERSION);
+        settings_map.insert("splits", splits_list);
+        Some(settings_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+    use ugly_widget::radio_button::options_value;
+
+    #[test]
+    fn splits_round_trip_through_the_settings_map() {
+        assert!(SplitsPreset::None.asr_settings_map().is_none());
+        for o in SplitsPreset::radio_button_options() {
+            let (Some(splits), Some(m)) = (o.value.splits(), o.value.asr_settings_map()) else {
+                continue;
+            };
+            assert_eq!(
+                m.get("script_name").and_then(|v| v.get_string()).as_deref(),
+                Some(this_script_name())
+            );
+            assert_eq!(
+                m.get("settings_version").and_then(|v| v.get_i64()),
+                Some(SETTINGS_VERSION)
+            );
+            let list = m.get("splits").and_then(|v| v.get_list()).unwrap();
+            let read: Vec<Option<Split>> = list
+                .iter()
+                .map(|v| options_value::<Split>(&v.get_string()?))
+                .collect();
+            let expected: Vec<Option<Split>> = splits.iter().cloned().map(Some).collect();
+            assert_eq!(read, expected, "{}", o.key);
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use core::mem;
+
+use asr::timer::TimerState;
+use asr::Process;
+
+use crate::game_time::GameTime;
+use crate::hit_counter::DASH;
+use crate::hollow_knight_memory::*;
+use crate::timer::{Resettable, Timer};
+
+const FOCUS_HEALS: usize = 0;
+const MASKS_HEALED: usize = 1;
+const SOUL_SPENT: usize = 2;
+const SPELLS_CAST: usize = 3;
+const STATS: usize = 4;
+
+const RUN_NAMES: [&str; STATS] = ["focus heals", "masks healed", "soul spent", "spells cast"];
+const SEGMENT_NAMES: [&str; STATS] = [
+    "segment focus heals",
+    "segment masks healed",
+    "segment soul spent",
+    "segment spells cast",
+];
+const BEST_NAMES: [&str; STATS] = [
+    "best segment focus heals",
+    "best segment masks healed",
+    "best segment soul spent",
+    "best segment spells cast",
+];
+
+type Stats = [i64; STATS];
+
+/// Health, soul, and focusing from the last tick while playing
+#[derive(Clone, Copy)]
+struct LastResources {
+    health: i32,
+    soul: i32,
+    focusing: bool,
+}
+
+/// Tracks focus heals, masks healed, soul spent, and spells cast,
+/// for the run and for each segment.
+pub struct ResourceTracker {
+    /// Whether to keep the lowest stats of each completed segment across attempts
+    save_bests: bool,
+    run: Stats,
+    /// Vector up to length n, indexed like HitCounter::segments_hits
+    segments: Vec<Stats>,
+    /// Vector up to length n, the lowest stats of each completed segment
+    bests: Vec<Option<Stats>>,
+    /// Index into the list of autosplits including start and end
+    i: usize,
+    /// Number of autosplits including both start and end
+    n: usize,
+    /// Whether the attempt ended since the last update
+    ended: bool,
+    last: Option<LastResources>,
+    /// The values last set for the run, segment, and best variables
+    published: [Option<i64>; STATS * 3],
+}
+
+impl Resettable for ResourceTracker {
+    fn ended(&mut self) {
+        self.ended = true;
+    }
+    fn reset(&mut self) {
+        if self.save_bests {
+            store_resource_bests(&self.bests);
+        }
+        self.run = [0; STATS];
+        self.segments = Vec::new();
+        self.i = 0;
+        self.ended = false;
+        self.last = None;
+        self.publish();
+    }
+}
+
+impl ResourceTracker {
+    pub fn new(n: usize, save_bests: bool) -> ResourceTracker {
+        let bests = if save_bests {
+            load_resource_bests().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        let mut tracker = ResourceTracker {
+            save_bests,
+            run: [0; STATS],
+            segments: Vec::new(),
+            bests,
+            i: 0,
+            n,
+            ended: false,
+            last: None,
+            published: [None; STATS * 3],
+        };
+        tracker.publish();
+        tracker
+    }
+
+    fn segment(&self, i: usize) -> Stats {
+        self.segments.get(i).copied().unwrap_or_default()
+    }
+
+    fn add(&mut self, stat: usize, k: i64) {
+        self.run[stat] += k;
+        self.segments
+            .resize(max(self.segments.len(), self.i + 1), [0; STATS]);
+        self.segments[self.i][stat] += k;
+    }
+
+    /// Sets the variables that have changed since they were last set
+    fn publish(&mut self) {
+        let segment = self.segment(self.i);
+        let (run_slots, rest) = self.published.split_at_mut(STATS);
+        let (segment_slots, best_slots) = rest.split_at_mut(STATS);
+        for (stat, value) in self.run.iter().enumerate() {
+            set_variable_if_changed(&mut run_slots[stat], RUN_NAMES[stat], *value);
+            set_variable_if_changed(&mut segment_slots[stat], SEGMENT_NAMES[stat], segment[stat]);
+        }
+        if !self.save_bests {
+            return;
+        }
+        let best = self.bests.get(self.i).copied().flatten();
+        for (stat, slot) in best_slots.iter_mut().enumerate() {
+            match best {
+                Some(b) => set_variable_if_changed(slot, BEST_NAMES[stat], b[stat]),
+                None => {
+                    if slot.take().is_some() {
+                        asr::timer::set_variable(BEST_NAMES[stat], DASH);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves between segments like the HitCounter does:
+    /// stats on skipped segments move forward, stats on undone segments move back,
+    /// and only completed segments count towards bests.
+    fn update_index(&mut self, timer: &Timer) {
+        let i = timer.i();
+        if i == self.i && !self.ended {
+            return;
+        }
+        self.n = timer.n();
+        let reached = if self.ended { self.n } else { i };
+        self.segments.resize(
+            max(self.segments.len(), max(reached, self.i) + 1),
+            [0; STATS],
+        );
+        if self.i < reached {
+            for j in self.i..reached {
+                if j == 0 {
+                    continue;
+                }
+                if timer.skipped(j) {
+                    let skipped = mem::take(&mut self.segments[j]);
+                    add_stats(&mut self.segments[j + 1], skipped);
+                } else if self.save_bests {
+                    self.add_best(j);
+                }
+            }
+        } else if 0 < reached && reached < self.i {
+            for k in reached + 1..=self.i {
+                let undone = mem::take(&mut self.segments[k]);
+                add_stats(&mut self.segments[reached], undone);
+            }
+        }
+        if self.ended && self.save_bests {
+            store_resource_bests(&self.bests);
+        }
+        self.ended = false;
+        self.i = i;
+    }
+
+    fn add_best(&mut self, j: usize) {
+        self.bests.resize(max(self.bests.len(), j + 1), None);
+        let segment = self.segments[j];
+        self.bests[j] = Some(match self.bests[j] {
+            None => segment,
+            Some(mut best) => {
+                for (b, s) in best.iter_mut().zip(segment) {
+                    *b = min(*b, s);
+                }
+                best
+            }
+        });
+    }
+}
+
+impl GameTime for ResourceTracker {
+    /// Sets the resource variables, but does not set game time
+    fn update_variables(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        self.update_index(timer);
+
+        // only count resources if timer is running
+        if asr::timer::state() != TimerState::Running {
+            self.publish();
+            return;
+        }
+
+        let playing = game_manager_finder.get_game_state(process) == Some(GAME_STATE_PLAYING)
+            && game_manager_finder.at_bench(process) == Some(false);
+        let (true, Some(health), Some(mpcharge), Some(mp_reserve), Some(focusing)) = (
+            playing,
+            game_manager_finder.get_health(process),
+            game_manager_finder.get_mpcharge(process),
+            game_manager_finder.mp_reserve(process),
+            game_manager_finder.focusing(process),
+        ) else {
+            self.last = None;
+            self.publish();
+            return;
+        };
+        let soul = mpcharge + mp_reserve;
+        if let Some(last) = self.last {
+            if last.health < health {
+                self.add(MASKS_HEALED, (health - last.health) as i64);
+                if focusing || last.focusing {
+                    self.add(FOCUS_HEALS, 1);
+                }
+            }
+            if soul < last.soul {
+                self.add(SOUL_SPENT, (last.soul - soul) as i64);
+                // focus drains soul gradually, spells take it all at once
+                if !focusing && !last.focusing {
+                    self.add(SPELLS_CAST, 1);
+                }
+            }
+        }
+        self.last = Some(LastResources {
+            health,
+            soul,
+            focusing,
+        });
+        self.publish();
+    }
+
+    /// Does not set game time, only variables
+    fn update_game_time(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) {
+        self.update_variables(timer, process, game_manager_finder);
+    }
+}
+
+fn add_stats(a: &mut Stats, b: Stats) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x += y;
+    }
+}
+
+fn set_variable_if_changed(published: &mut Option<i64>, name: &str, value: i64) {
+    if *published != Some(value) {
+        asr::timer::set_variable_int(name, value);
+        *published = Some(value);
+    }
+}
+
+fn load_resource_bests() -> Option<Vec<Option<Stats>>> {
+    let v = asr::settings::Map::load().get("resource_bests")?;
+    let l = v.get_list()?;
+    let mut r = Vec::new();
+    for e in l.iter() {
+        let Some(es) = e.get_list() else {
+            break;
+        };
+        let values: Vec<i64> = es.iter().filter_map(|x| x.get_i64()).collect();
+        r.push(<Stats>::try_from(values.as_slice()).ok());
+    }
+    Some(r)
+}
+
+fn store_resource_bests(bests: &[Option<Stats>]) {
+    loop {
+        let l = asr::settings::List::new();
+        for best in bests {
+            let es = asr::settings::List::new();
+            for x in best.iter().flatten() {
+                es.push(*x);
+            }
+            l.push(es);
+        }
+        let m = asr::settings::Map::load();
+        let old = m.clone();
+        m.insert("resource_bests", l);
+        if m.store_if_unchanged(&old) {
+            break;
+        }
+    }
+}
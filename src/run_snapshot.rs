@@ -0,0 +1,121 @@
+use alloc::format;
+
+use asr::settings::Map;
+
+use crate::game_time::{GameTime, GameTimePlusVars};
+use crate::hollow_knight_memory::PlayerDataStore;
+use crate::timer::{is_timer_state_between_runs, Timer};
+use crate::unstable::maybe_timer_current_split_index;
+
+/// Keeps a snapshot of the run in progress in the settings map,
+/// so that the run can continue if the autosplitter is reloaded mid-run.
+pub struct RunSnapshot {
+    last_i: usize,
+    last_between_runs: bool,
+}
+
+impl RunSnapshot {
+    pub fn new() -> RunSnapshot {
+        RunSnapshot {
+            last_i: 0,
+            // so that a snapshot left over from an old run is removed if there's no run now
+            last_between_runs: false,
+        }
+    }
+
+    /// Stores a snapshot whenever the split index changes while a run is in progress,
+    /// and removes it when the run resets or ends.
+    pub fn update(&mut self, timer: &Timer, game_time: &GameTimePlusVars, pds: &PlayerDataStore) {
+        let between_runs = timer.is_timer_state_between_runs();
+        let changed = between_runs != self.last_between_runs || timer.i() != self.last_i;
+        self.last_between_runs = between_runs;
+        self.last_i = timer.i();
+        if !changed {
+            return;
+        }
+        if between_runs {
+            remove_run_snapshot();
+        } else {
+            store_run_snapshot(timer, game_time, pds);
+        }
+    }
+}
+
+fn store_run_snapshot(timer: &Timer, game_time: &GameTimePlusVars, pds: &PlayerDataStore) {
+    let snapshot = Map::new();
+    snapshot.insert("i", timer.i() as i64);
+    snapshot.insert("n", timer.n() as i64);
+    game_time.snapshot_into(&snapshot);
+    pds.snapshot_into(&snapshot);
+    loop {
+        let m = Map::load();
+        let old = m.clone();
+        m.insert("run_snapshot", snapshot.clone());
+        if m.store_if_unchanged(&old) {
+            break;
+        }
+    }
+}
+
+/// Removes the run snapshot, so that a later run can't restore it
+fn remove_run_snapshot() {
+    loop {
+        let old = Map::load();
+        if old.get("run_snapshot").is_none() {
+            break;
+        }
+        let m = Map::new();
+        for key in old.keys().filter(|k| k != "run_snapshot") {
+            if let Some(v) = old.get(&key) {
+                m.insert(&key, &v);
+            }
+        }
+        if m.store_if_unchanged(&old) {
+            break;
+        }
+    }
+}
+
+/// Restores the run snapshot if the timer shows a run in progress,
+/// and the snapshot matches it.
+pub fn restore_run_snapshot(
+    timer: &mut Timer,
+    game_time: &mut GameTimePlusVars,
+    pds: &mut PlayerDataStore,
+) -> bool {
+    if is_timer_state_between_runs(asr::timer::state()) {
+        return false;
+    }
+    let Some(snapshot) = Map::load().get("run_snapshot").and_then(|v| v.get_map()) else {
+        return false;
+    };
+    let (Some(i), Some(n)) = (
+        snapshot.get("i").and_then(|v| v.get_i64()),
+        snapshot.get("n").and_then(|v| v.get_i64()),
+    ) else {
+        return false;
+    };
+    if n as usize != timer.n() {
+        asr::print_message(&format!(
+            "Ignoring run snapshot for {} splits, with {} splits now",
+            n,
+            timer.n()
+        ));
+        return false;
+    }
+    if let Some(index) = maybe_timer_current_split_index() {
+        if index + 1 != i as i32 {
+            asr::print_message(&format!(
+                "Ignoring run snapshot at split {}, with the timer at split {}",
+                i,
+                index + 1
+            ));
+            return false;
+        }
+    }
+    timer.restore(i as usize);
+    game_time.restore_from(timer, &snapshot);
+    pds.restore_from(&snapshot);
+    asr::print_message(&format!("Restored run snapshot at split {}", i));
+    true
+}
@@ -1,25 +1,28 @@
 use alloc::format;
+use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use asr::settings::gui::{Gui, Title};
+use asr::settings::gui::{Gui, Title, Widget};
 #[cfg(not(target_os = "unknown"))]
-use asr::{
-    settings::gui::{FileSelect, Widget},
-    watcher::Pair,
-};
+use asr::{settings::gui::FileSelect, watcher::Pair};
 
-#[cfg(not(target_os = "unknown"))]
-use ugly_widget::{args::SetHeadingLevel, ugly_list::UglyListArgs};
 use ugly_widget::{
+    args::SetHeadingLevel,
     radio_button::{options_str, RadioButtonOptions},
     store::{StoreGui, StoreWidget},
-    ugly_list::UglyList,
+    ugly_list::{UglyList, UglyListArgs},
 };
 
 #[cfg(not(target_os = "unknown"))]
 use crate::auto_splitter_settings::asr_settings_from_file;
-use crate::{auto_splitter_settings::wait_asr_settings_init, splits::Split};
+use crate::{
+    auto_splitter_settings::wait_asr_settings_init,
+    flower_quest::FlowerQuest,
+    game_state_vars::{GameStateVar, GameStateVars},
+    presets::SplitsPreset,
+    split_entry::SplitEntry,
+};
 
 #[derive(Gui)]
 pub struct SettingsGui {
@@ -33,9 +36,82 @@ pub struct SettingsGui {
     timing_method: TimingMethod,
     /// Hit Counter
     hit_counter: HitsMethod,
+    /// Hit Penalty
+    ///
+    /// Used by the Load Removed Time + Hit Penalty timing method
+    hit_penalty: HitPenalty,
+    /// Delicate Flower Broken
+    ///
+    /// What to do when the Delicate Flower breaks during a run
+    flower_broken: FlowerBrokenAction,
+    /// Arm Splits
+    ///
+    /// Only splits once a split's condition has been false while playing,
+    /// since it became the current split,
+    /// so that loading a save that already satisfies it doesn't split right away
+    arm_splits: bool,
+    /// Load Preset Splits
+    ///
+    /// Check Replace Splits With Preset to load it
+    preset: SplitsPreset,
+    /// Replace Splits With Preset
+    ///
+    /// Replaces all of the splits below with the selected preset's splits
+    preset_replace: bool,
     /// Splits
     #[heading_level = 1]
-    splits: UglyList<Split>,
+    splits: UglyList<SplitEntry>,
+    /// Custom Variables
+    _custom_variables: Title,
+    /// Geo
+    var_geo: bool,
+    /// Essence
+    var_essence: bool,
+    /// Grubs
+    var_grubs: bool,
+    /// Masks
+    var_masks: bool,
+    /// Charms
+    var_charms: bool,
+    /// Completion %
+    ///
+    /// Computed with the same formula as the game, up to 112%
+    var_completion: bool,
+    /// Play Time
+    ///
+    /// The save file's own play time, counted from the start of the run,
+    /// alongside the timing method
+    var_play_time: bool,
+    /// Deaths and Shades
+    ///
+    /// Deaths, hazard respawns, dream falls, shade kills, and geo lost to shades over the run
+    var_deaths: bool,
+    /// Delicate Flower
+    ///
+    /// Whether the flower is carried, broken, or delivered, and to how many recipients
+    var_flower: bool,
+    /// Focus / Soul Usage
+    ///
+    /// Focus heals, masks healed, soul spent, and spells cast,
+    /// for the run and for the current segment
+    resource_tracking: ResourceTracking,
+    /// Route Making
+    _route_making: Title,
+    /// Log Scene Data Items
+    ///
+    /// Logs the scene and id of every persistent item once each time this is checked,
+    /// for use with Scene Data splits
+    log_scene_data: bool,
+    /// Log Level
+    ///
+    /// How much the auto splitter logs about scenes, load removal, and Scene Data,
+    /// for bug reports
+    log_level: LogLevel,
+    /// Split Diagnostics
+    ///
+    /// Shows how the current split was evaluated as the split diagnostics variable,
+    /// and logs the values read whenever they change
+    split_diagnostics: bool,
 }
 
 impl StoreGui for SettingsGui {
@@ -50,11 +126,21 @@ impl StoreGui for SettingsGui {
                 let hit_counter_args = <HitsMethod as Widget>::Args::default();
                 self.hit_counter
                     .update_from(&settings_map, "hit_counter", hit_counter_args);
-                let mut splits_args = UglyListArgs::default();
-                splits_args.set_heading_level(1);
-                self.splits
-                    .update_from(&settings_map, "splits", splits_args);
+                self.hit_penalty
+                    .update_from(&settings_map, "hit_penalty", ());
+                self.flower_broken
+                    .update_from(&settings_map, "flower_broken", ());
+                self.update_splits_from(&settings_map);
+            }
+        }
+        if self.preset_replace {
+            // only replace the splits once the runner confirms it,
+            // so that picking a preset doesn't silently throw away their own splits
+            if let Some(settings_map) = self.preset.asr_settings_map() {
+                asr::print_message(&format!("preset {:?}", self.preset));
+                self.update_splits_from(&settings_map);
             }
+            self.preset_replace = false;
         }
     }
 
@@ -63,19 +149,85 @@ impl StoreGui for SettingsGui {
             .timing_method
             .insert_into(settings_map, "timing_method");
         let b = self.hit_counter.insert_into(settings_map, "hit_counter");
-        let c = self.splits.insert_into(settings_map, "splits");
-        a || b || c
+        let c = self.preset.insert_into(settings_map, "preset");
+        let d = self.splits.insert_into(settings_map, "splits");
+        let e = [
+            self.hit_penalty.insert_into(settings_map, "hit_penalty"),
+            self.flower_broken
+                .insert_into(settings_map, "flower_broken"),
+            self.arm_splits.insert_into(settings_map, "arm_splits"),
+            self.preset_replace
+                .insert_into(settings_map, "preset_replace"),
+            self.var_geo.insert_into(settings_map, "var_geo"),
+            self.var_essence.insert_into(settings_map, "var_essence"),
+            self.var_grubs.insert_into(settings_map, "var_grubs"),
+            self.var_masks.insert_into(settings_map, "var_masks"),
+            self.var_charms.insert_into(settings_map, "var_charms"),
+            self.var_completion
+                .insert_into(settings_map, "var_completion"),
+            self.var_play_time
+                .insert_into(settings_map, "var_play_time"),
+            self.var_deaths.insert_into(settings_map, "var_deaths"),
+            self.var_flower.insert_into(settings_map, "var_flower"),
+            self.resource_tracking
+                .insert_into(settings_map, "resource_tracking"),
+            self.log_scene_data
+                .insert_into(settings_map, "log_scene_data"),
+            self.log_level.insert_into(settings_map, "log_level"),
+            self.split_diagnostics
+                .insert_into(settings_map, "split_diagnostics"),
+        ]
+        .contains(&true);
+        a || b || c || d || e
     }
 }
 
 impl SettingsGui {
+    /// Replaces the splits list with the one in an imported settings map.
+    fn update_splits_from(&mut self, settings_map: &asr::settings::Map) {
+        let mut splits_args = UglyListArgs::default();
+        splits_args.set_heading_level(1);
+        self.splits.update_from(settings_map, "splits", splits_args);
+    }
+
+    /// Fills in the scene, id, and value of the split at index `i`
+    /// from a Scene Data item taken from the game, and unchecks Take Scene Data Item
+    pub fn take_scene_data_item(&mut self, i: usize, scene: &str, id: &str, value: Option<i32>) {
+        let Some(mut entry) = self.splits.get_list().get(i).map(|&e| e.clone()) else {
+            return;
+        };
+        entry.scene = scene.to_string();
+        entry.id = id.to_string();
+        if let Some(value) = value {
+            entry.value = value;
+        }
+        entry.take_item = false;
+        loop {
+            let settings_map = asr::settings::Map::load();
+            let old = settings_map.clone();
+            entry.insert_into(&settings_map, &format!("splits_{}_item", i));
+            self.update_from(&settings_map);
+            self.post_update();
+            StoreGui::insert_into(self, &settings_map);
+            if settings_map.store_if_unchanged(&old) {
+                break;
+            }
+        }
+    }
+
     pub fn get_timing_method(&self) -> TimingMethod {
         self.timing_method
     }
     pub fn get_hit_counter(&self) -> HitsMethod {
         self.hit_counter
     }
-    pub fn get_splits(&self) -> Vec<Split> {
+    pub fn get_hit_penalty(&self) -> HitPenalty {
+        self.hit_penalty
+    }
+    pub fn get_arm_splits(&self) -> bool {
+        self.arm_splits
+    }
+    pub fn get_splits(&self) -> Vec<SplitEntry> {
         self.splits
             .get_list()
             .into_iter()
@@ -83,6 +235,20 @@ impl SettingsGui {
             .collect()
     }
 
+    pub fn get_game_state_vars(&self) -> Vec<GameStateVar> {
+        [
+            (self.var_geo, GameStateVar::Geo),
+            (self.var_essence, GameStateVar::Essence),
+            (self.var_grubs, GameStateVar::Grubs),
+            (self.var_masks, GameStateVar::Masks),
+            (self.var_charms, GameStateVar::Charms),
+            (self.var_completion, GameStateVar::Completion),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, v)| enabled.then_some(v))
+        .collect()
+    }
+
     pub async fn wait_load_merge_register() -> SettingsGui {
         wait_asr_settings_init().await;
         let mut gui = SettingsGui::register();
@@ -112,7 +278,117 @@ impl SettingsGui {
         }
     }
 
-    pub fn check_splits<'a>(&self, splits: &'a mut Vec<Split>) -> Option<&'a [Split]> {
+    pub fn check_hit_penalty(&self, hit_penalty: &mut HitPenalty) -> Option<HitPenalty> {
+        let new_hit_penalty = self.get_hit_penalty();
+        if new_hit_penalty != *hit_penalty {
+            *hit_penalty = new_hit_penalty;
+            asr::print_message(&format!("hit_penalty: {:?}", hit_penalty));
+            Some(new_hit_penalty)
+        } else {
+            None
+        }
+    }
+
+    pub fn check_game_state_vars<'a>(
+        &self,
+        game_state_vars: &'a mut GameStateVars,
+    ) -> Option<&'a [GameStateVar]> {
+        let new_enabled = self.get_game_state_vars();
+        if new_enabled != game_state_vars.enabled() {
+            game_state_vars.renew(new_enabled);
+            asr::print_message(&format!("game_state_vars: {:?}", game_state_vars.enabled()));
+            Some(game_state_vars.enabled())
+        } else {
+            None
+        }
+    }
+
+    pub fn check_flower_quest<'a>(
+        &self,
+        flower_quest: &'a mut FlowerQuest,
+    ) -> Option<&'a FlowerQuest> {
+        let (new_var, new_broken_action) = (self.var_flower, self.flower_broken);
+        if new_var != flower_quest.var() || new_broken_action != flower_quest.broken_action() {
+            flower_quest.renew(new_var, new_broken_action);
+            asr::print_message(&format!(
+                "flower_quest: var {:?}, broken {:?}",
+                new_var, new_broken_action
+            ));
+            Some(flower_quest)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_flower_var(&self) -> bool {
+        self.var_flower
+    }
+
+    pub fn get_flower_broken(&self) -> FlowerBrokenAction {
+        self.flower_broken
+    }
+
+    pub fn get_play_time_var(&self) -> bool {
+        self.var_play_time
+    }
+
+    pub fn check_play_time_var(&self, play_time_var: &mut bool) -> Option<bool> {
+        let new_play_time_var = self.get_play_time_var();
+        if new_play_time_var != *play_time_var {
+            *play_time_var = new_play_time_var;
+            asr::print_message(&format!("play_time_var: {:?}", play_time_var));
+            Some(new_play_time_var)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_deaths_var(&self) -> bool {
+        self.var_deaths
+    }
+
+    pub fn check_deaths_var(&self, deaths_var: &mut bool) -> Option<bool> {
+        let new_deaths_var = self.get_deaths_var();
+        if new_deaths_var != *deaths_var {
+            *deaths_var = new_deaths_var;
+            asr::print_message(&format!("deaths_var: {:?}", deaths_var));
+            Some(new_deaths_var)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_resource_tracking(&self) -> ResourceTracking {
+        self.resource_tracking
+    }
+
+    pub fn check_resource_tracking(
+        &self,
+        resource_tracking: &mut ResourceTracking,
+    ) -> Option<ResourceTracking> {
+        let new_resource_tracking = self.get_resource_tracking();
+        if new_resource_tracking != *resource_tracking {
+            *resource_tracking = new_resource_tracking;
+            asr::print_message(&format!("resource_tracking: {:?}", resource_tracking));
+            Some(new_resource_tracking)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_log_scene_data(&self) -> bool {
+        self.log_scene_data
+    }
+
+    pub fn get_log_level(&self) -> LogLevel {
+        self.log_level
+    }
+
+    pub fn get_split_diagnostics(&self) -> bool {
+        self.split_diagnostics
+    }
+
+    pub fn check_splits<'a>(&self, splits: &'a mut Vec<SplitEntry>) -> Option<&'a [SplitEntry]> {
         let new_splits = self.get_splits();
         if new_splits != *splits {
             *splits = new_splits;
@@ -133,6 +409,19 @@ pub enum TimingMethod {
     HitsDreamFalls,
     /// Hits / damage
     HitsDamage,
+    /// Hits / masks lost
+    ///
+    /// Counts health lost in masks including lifeblood, so a double-damage hit counts as 2
+    HitsMasksLost,
+    /// In-Game Play Time
+    ///
+    /// The save file's own play time, counted from the start of the run
+    PlayTime,
+    /// Load Removed Time + Hit Penalty
+    ///
+    /// Adds the Hit Penalty for every hit counted by the Hit Counter,
+    /// or for every hit of damage if the Hit Counter is None
+    HitPenalty,
 }
 
 impl StoreWidget for TimingMethod {
@@ -158,6 +447,10 @@ pub enum HitsMethod {
     HitsDreamFalls,
     /// Hits / damage
     HitsDamage,
+    /// Hits / masks lost
+    ///
+    /// Counts health lost in masks including lifeblood, so a double-damage hit counts as 2
+    HitsMasksLost,
 }
 
 impl StoreWidget for HitsMethod {
@@ -173,3 +466,133 @@ impl StoreWidget for HitsMethod {
         true
     }
 }
+
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum HitPenalty {
+    /// 1 second per hit
+    Seconds1,
+    /// 5 seconds per hit
+    #[default]
+    Seconds5,
+    /// 10 seconds per hit
+    Seconds10,
+    /// 30 seconds per hit
+    Seconds30,
+    /// 60 seconds per hit
+    Seconds60,
+}
+
+impl HitPenalty {
+    pub fn seconds(&self) -> i64 {
+        match self {
+            HitPenalty::Seconds1 => 1,
+            HitPenalty::Seconds5 => 5,
+            HitPenalty::Seconds10 => 10,
+            HitPenalty::Seconds30 => 30,
+            HitPenalty::Seconds60 => 60,
+        }
+    }
+}
+
+impl StoreWidget for HitPenalty {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum FlowerBrokenAction {
+    /// Keep running
+    #[default]
+    None,
+    /// Reset the run
+    Reset,
+    /// Skip flower splits
+    ///
+    /// Skips the current split if it needs the flower delivered,
+    /// along with the flower splits right after it
+    SkipFlowerSplits,
+}
+
+impl StoreWidget for FlowerBrokenAction {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum LogLevel {
+    /// Off
+    #[default]
+    Off,
+    /// Info
+    ///
+    /// Scene transitions and load removal pauses
+    Info,
+    /// Debug
+    ///
+    /// Also scene names, game states, and Scene Data changes
+    Debug,
+    /// Trace
+    ///
+    /// Also every loaded scene and rescued grubs
+    Trace,
+}
+
+impl StoreWidget for LogLevel {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum ResourceTracking {
+    /// None
+    #[default]
+    None,
+    /// Variables
+    Variables,
+    /// Variables and segment bests
+    ///
+    /// Also saves the lowest of each variable for every completed segment,
+    /// as best segment variables
+    VariablesAndBests,
+}
+
+impl StoreWidget for ResourceTracking {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
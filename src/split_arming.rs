@@ -0,0 +1,145 @@
+use crate::hollow_knight_memory::*;
+use crate::timer::SplitterAction;
+
+/// Holds back splits until their condition has been seen false while playing,
+/// since they became the current split,
+/// so that loading a save which already satisfies a split doesn't split right away.
+pub struct SplitArming {
+    /// The index of the split being armed
+    i: usize,
+    armed: bool,
+}
+
+impl SplitArming {
+    pub fn new() -> SplitArming {
+        SplitArming { i: 0, armed: false }
+    }
+
+    /// Passes instead of splitting while the split at index `i` isn't armed yet.
+    ///
+    /// The split is armed when it passes while playing,
+    /// and disarmed again when it passes on the main menu or on the way there,
+    /// since the main menu reads the defaults rather than the save that gets loaded next.
+    /// Menu splits still work, since they pass while playing before the menu.
+    /// Loading a save from the menu disarms the split whatever its condition,
+    /// since any split there comes from the save rather than the run.
+    pub fn filter(
+        &mut self,
+        i: usize,
+        a: SplitterAction,
+        game_state: Option<i32>,
+        ss: &SceneStore,
+    ) -> SplitterAction {
+        if i != self.i {
+            self.i = i;
+            self.armed = false;
+        }
+        let pair = ss.pair();
+        if is_menu(pair.old) && game_state == Some(GAME_STATE_LOADING) {
+            self.armed = false;
+        }
+        match a {
+            SplitterAction::Split if !self.armed => SplitterAction::Pass,
+            SplitterAction::Pass => {
+                if is_menu(pair.current) || game_state == Some(GAME_STATE_MAIN_MENU) {
+                    self.armed = false;
+                } else if game_state == Some(GAME_STATE_PLAYING) {
+                    self.armed = true;
+                }
+                SplitterAction::Pass
+            }
+            a => a,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn scenes(old: &str, current: &str) -> SceneStore {
+        let mut ss = SceneStore::new();
+        ss.new_curr_scene_name(Some(old.to_string()));
+        ss.new_next_scene_name(Some(current.to_string()));
+        ss
+    }
+
+    const PASS: SplitterAction = SplitterAction::Pass;
+    const SPLIT: SplitterAction = SplitterAction::Split;
+
+    #[test]
+    fn splits_only_after_passing_while_playing() {
+        let mut arming = SplitArming::new();
+        let ss = scenes("Town", "Crossroads_01");
+        let playing = Some(GAME_STATE_PLAYING);
+        assert_eq!(arming.filter(1, SPLIT, playing, &ss), PASS);
+        assert_eq!(arming.filter(1, PASS, playing, &ss), PASS);
+        assert_eq!(arming.filter(1, SPLIT, playing, &ss), SPLIT);
+        // the next split starts out disarmed
+        assert_eq!(arming.filter(2, SPLIT, playing, &ss), PASS);
+    }
+
+    #[test]
+    fn quitting_out_and_loading_a_save_disarms() {
+        let mut arming = SplitArming::new();
+        let playing = Some(GAME_STATE_PLAYING);
+        arming.filter(1, PASS, playing, &scenes("Town", "Crossroads_01"));
+        // quitting out, where the condition still reads the old save
+        let quit = scenes("Crossroads_01", QUIT_TO_MENU);
+        assert_eq!(
+            arming.filter(1, PASS, Some(GAME_STATE_EXITING_LEVEL), &quit),
+            PASS
+        );
+        // loading a save which already satisfies the split
+        let load = scenes(MENU_TITLE, "Crossroads_01");
+        assert_eq!(
+            arming.filter(1, SPLIT, Some(GAME_STATE_LOADING), &load),
+            PASS
+        );
+        assert_eq!(arming.filter(1, SPLIT, playing, &load), PASS);
+    }
+
+    #[test]
+    fn loading_a_save_disarms_even_without_passing_on_the_menu() {
+        let mut arming = SplitArming::new();
+        let playing = Some(GAME_STATE_PLAYING);
+        arming.filter(1, PASS, playing, &scenes("Town", "Crossroads_01"));
+        let load = scenes(MENU_TITLE, "Crossroads_01");
+        assert_eq!(
+            arming.filter(1, SPLIT, Some(GAME_STATE_LOADING), &load),
+            PASS
+        );
+        assert_eq!(arming.filter(1, SPLIT, playing, &load), PASS);
+        assert_eq!(arming.filter(1, PASS, playing, &load), PASS);
+        assert_eq!(arming.filter(1, SPLIT, playing, &load), SPLIT);
+    }
+
+    #[test]
+    fn menu_splits_still_split() {
+        let mut arming = SplitArming::new();
+        arming.filter(
+            1,
+            PASS,
+            Some(GAME_STATE_PLAYING),
+            &scenes("Town", "Crossroads_01"),
+        );
+        let menu = scenes(QUIT_TO_MENU, MENU_TITLE);
+        assert_eq!(
+            arming.filter(1, SPLIT, Some(GAME_STATE_MAIN_MENU), &menu),
+            SPLIT
+        );
+    }
+
+    #[test]
+    fn the_main_menu_game_state_disarms() {
+        let mut arming = SplitArming::new();
+        let ss = scenes("Town", "Crossroads_01");
+        arming.filter(1, PASS, Some(GAME_STATE_PLAYING), &ss);
+        assert_eq!(
+            arming.filter(1, PASS, Some(GAME_STATE_MAIN_MENU), &ss),
+            PASS
+        );
+        assert_eq!(arming.filter(1, SPLIT, Some(GAME_STATE_PLAYING), &ss), PASS);
+    }
+}
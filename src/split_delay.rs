@@ -0,0 +1,273 @@
+use asr::settings::gui::Gui;
+use asr::time::Duration;
+use asr::time_util::Instant;
+use asr::Process;
+use ugly_widget::{
+    radio_button::{options_str, RadioButtonOptions},
+    store::StoreWidget,
+};
+
+use crate::hollow_knight_memory::GameManagerFinder;
+use crate::load_remover::LoadRemover;
+use crate::log_level::log;
+use crate::split_entry::SplitEntry;
+use crate::timer::{SplitterAction, Timer};
+
+/// How long to wait after a split's condition before splitting,
+/// for the settings GUI, which can't edit numbers.
+/// Other delays can still be set in milliseconds in the splits file.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum Delay {
+    /// No delay
+    #[default]
+    None,
+    /// 0.5 seconds
+    HalfSecond,
+    /// 1 second
+    OneSecond,
+    /// 1.5 seconds
+    OneAndAHalfSeconds,
+    /// 2 seconds
+    TwoSeconds,
+    /// 2.5 seconds
+    TwoAndAHalfSeconds,
+    /// 3 seconds
+    ThreeSeconds,
+    /// 4 seconds
+    FourSeconds,
+    /// 5 seconds
+    FiveSeconds,
+}
+
+impl StoreWidget for Delay {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+impl Delay {
+    pub fn ms(&self) -> i64 {
+        match self {
+            Delay::None => 0,
+            Delay::HalfSecond => 500,
+            Delay::OneSecond => 1000,
+            Delay::OneAndAHalfSeconds => 1500,
+            Delay::TwoSeconds => 2000,
+            Delay::TwoAndAHalfSeconds => 2500,
+            Delay::ThreeSeconds => 3000,
+            Delay::FourSeconds => 4000,
+            Delay::FiveSeconds => 5000,
+        }
+    }
+
+    /// The option for a delay of `ms` milliseconds, if there is one
+    pub fn from_ms(ms: i64) -> Option<Delay> {
+        Delay::radio_button_options()
+            .into_iter()
+            .map(|o| o.value)
+            .find(|d| d.ms() == ms)
+    }
+}
+
+/// Holds a split back for a delay of load-removed time after its condition,
+/// so that a split can land at the end of an animation the game doesn't expose.
+///
+/// Load-removed time is measured here, like in `HitPenalty`,
+/// so that the delay doesn't depend on which timing method is selected.
+pub struct SplitDelay {
+    /// Only when some split has a delay, so that other runs don't read the load state twice a tick
+    load_remover: Option<LoadRemover>,
+    /// The index of the split waiting, and how much load-removed time it has left
+    waiting: Option<(usize, Duration)>,
+    /// Whether the time since the last tick counts towards the delay,
+    /// false on the first tick of a wait or after the load state was unknown
+    counting: bool,
+    /// When the last tick was
+    last_instant: Option<Instant>,
+}
+
+impl SplitDelay {
+    pub fn new(splits: &[SplitEntry]) -> SplitDelay {
+        SplitDelay {
+            load_remover: splits.iter().any(|e| 0 < e.delay_ms).then(LoadRemover::new),
+            waiting: None,
+            counting: false,
+            last_instant: None,
+        }
+    }
+
+    /// Whether the split at index `i` is waiting for its delay
+    pub fn is_waiting(&self, i: usize) -> bool {
+        self.waiting.is_some_and(|(j, _)| i == j)
+    }
+
+    /// Starts waiting instead of splitting when the split at index `i` has a delay
+    pub fn filter(&mut self, i: usize, e: &SplitEntry, a: SplitterAction) -> SplitterAction {
+        if 0 < i && 0 < e.delay_ms && a == SplitterAction::Split {
+            self.waiting = Some((i, Duration::milliseconds(e.delay_ms)));
+            self.counting = false;
+            SplitterAction::Pass
+        } else {
+            a
+        }
+    }
+
+    /// Forgets when the last tick was, when the game process is lost or attached again,
+    /// so that the time the game was away doesn't count towards the delay
+    pub fn detached(&mut self) {
+        self.counting = false;
+    }
+
+    /// Splits once the waiting split's delay has passed.
+    ///
+    /// Stops waiting if the timer moved away from the split in the meantime,
+    /// such as from a reset or a manual split, undo, or skip.
+    pub fn update(
+        &mut self,
+        timer: &Timer,
+        process: &Process,
+        game_manager_finder: &GameManagerFinder,
+    ) -> SplitterAction {
+        // The load remover follows the game state from tick to tick,
+        // so it has to see every tick, not only the ones spent waiting
+        let paused = self
+            .load_remover
+            .as_mut()
+            .and_then(|l| l.is_game_time_paused(process, game_manager_finder));
+        let now = Instant::now();
+        let elapsed = self.last_instant.map_or(Duration::ZERO, |last| now - last);
+        self.last_instant = Some(now);
+        self.count_down(
+            timer.i(),
+            timer.is_timer_state_between_runs(),
+            elapsed,
+            paused,
+        )
+    }
+
+    /// Counts `elapsed` time since the last tick towards the waiting split's delay,
+    /// unless the game time is `paused`, or unknown when `paused` is `None`.
+    /// The timer is at index `timer_i`, or `between_runs`.
+    fn count_down(
+        &mut self,
+        timer_i: usize,
+        between_runs: bool,
+        elapsed: Duration,
+        paused: Option<bool>,
+    ) -> SplitterAction {
+        let Some((i, left)) = self.waiting else {
+            return SplitterAction::Pass;
+        };
+        if timer_i != i || between_runs {
+            log!(
+                Info,
+                "Stopped waiting on the delay for split {}, since the timer moved to {}.",
+                i,
+                timer_i
+            );
+            self.waiting = None;
+            return SplitterAction::Pass;
+        }
+        let Some(paused) = paused else {
+            self.counting = false;
+            return SplitterAction::Pass;
+        };
+        let left = if self.counting && !paused {
+            left - elapsed
+        } else {
+            left
+        };
+        self.counting = true;
+        if left.is_positive() {
+            self.waiting = Some((i, left));
+            SplitterAction::Pass
+        } else {
+            self.waiting = None;
+            SplitterAction::Split
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASS: SplitterAction = SplitterAction::Pass;
+    const SPLIT: SplitterAction = SplitterAction::Split;
+
+    fn ms(n: i64) -> Duration {
+        Duration::milliseconds(n)
+    }
+
+    /// Waiting on a one second delay for the split at index 1
+    fn waiting() -> SplitDelay {
+        let e = SplitEntry {
+            delay_ms: 1000,
+            ..Default::default()
+        };
+        let mut d = SplitDelay {
+            load_remover: None,
+            waiting: None,
+            counting: false,
+            last_instant: None,
+        };
+        assert_eq!(d.filter(1, &e, SPLIT), PASS);
+        assert!(d.is_waiting(1));
+        d
+    }
+
+    #[test]
+    fn counts_down_while_unpaused() {
+        let mut d = waiting();
+        // the tick the wait starts on doesn't count
+        assert_eq!(d.count_down(1, false, ms(5000), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(600), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(300), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(100), Some(false)), SPLIT);
+        assert!(!d.is_waiting(1));
+    }
+
+    #[test]
+    fn does_not_count_down_while_paused() {
+        let mut d = waiting();
+        assert_eq!(d.count_down(1, false, ms(0), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(900), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(5000), Some(true)), PASS);
+        assert_eq!(d.count_down(1, false, ms(5000), None), PASS);
+        assert!(d.is_waiting(1));
+        assert_eq!(d.count_down(1, false, ms(100), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(100), Some(false)), SPLIT);
+    }
+
+    #[test]
+    fn reset_or_moving_the_timer_cancels_the_wait() {
+        let mut d = waiting();
+        assert_eq!(d.count_down(1, true, ms(0), Some(false)), PASS);
+        assert!(!d.is_waiting(1));
+        let mut d = waiting();
+        assert_eq!(d.count_down(2, false, ms(0), Some(false)), PASS);
+        assert!(!d.is_waiting(1));
+        // coming back to the split doesn't resume the wait
+        assert_eq!(d.count_down(1, false, ms(5000), Some(false)), PASS);
+    }
+
+    #[test]
+    fn detached_does_not_count_the_time_away() {
+        let mut d = waiting();
+        assert_eq!(d.count_down(1, false, ms(0), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(500), Some(false)), PASS);
+        d.detached();
+        assert_eq!(d.count_down(1, false, ms(60000), Some(false)), PASS);
+        assert!(d.is_waiting(1));
+        assert_eq!(d.count_down(1, false, ms(400), Some(false)), PASS);
+        assert_eq!(d.count_down(1, false, ms(100), Some(false)), SPLIT);
+    }
+}
@@ -0,0 +1,381 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use asr::settings::gui::{set_tooltip, BoolArgs, Gui, Widget};
+use asr::timer::TimerState;
+use asr::Process;
+use ugly_widget::{
+    radio_button::{options_str, RadioButtonOptions},
+    store::StoreWidget,
+};
+
+use crate::branches::Branch;
+use crate::deferred::Defer;
+use crate::diagnostics::SplitEvaluation;
+use crate::hollow_knight_memory::*;
+use crate::occurrence::{CountFrom, Occurrence};
+use crate::split_delay::Delay;
+use crate::splits::{self, Split};
+use crate::timer::{should_split, SplitterAction};
+
+/// A split in the splits list, along with the parameters that some splits need.
+///
+/// The split is stored under the item's key like a plain `Split`,
+/// and the parameters are stored in sibling keys such as `splits_3_item_scene`,
+/// so that splits lists without parameters are still read the same way.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SplitEntry {
+    pub split: Split,
+    /// Scene name, for Scene Data splits
+    pub scene: String,
+    /// Item id within the scene, for Scene Data splits
+    pub id: String,
+    /// Target value, for Persistent Int Item splits
+    pub value: i32,
+    /// Whether to take the scene, id, and value from the next Scene Data item that changes
+    pub take_item: bool,
+    /// Whether to arm this split even if arming isn't on for all splits
+    pub arm: bool,
+    /// Which occurrence of the split's condition to split on
+    pub occurrence: Occurrence,
+    /// Whether to count occurrences since the previous split or since the run started
+    pub count_from: CountFrom,
+    /// Whether to wait for the next transition or menu after the split's condition
+    pub defer: Defer,
+    /// Milliseconds of load-removed time to wait after the split's condition before splitting
+    pub delay_ms: i64,
+    /// Which alternative of a branch the split belongs to, if any
+    pub branch: Branch,
+}
+
+/// Target value of a Persistent Int Item split, for the settings GUI,
+/// which can't edit numbers.
+/// Other values can still be set in the splits file.
+#[derive(Clone, Copy, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
+pub enum ItemValue {
+    /// 0
+    #[default]
+    Zero,
+    /// 1
+    One,
+    /// 2
+    Two,
+    /// 3
+    Three,
+    /// 4
+    Four,
+    /// 5
+    Five,
+    /// 6
+    Six,
+    /// 7
+    Seven,
+    /// 8
+    Eight,
+    /// 9
+    Nine,
+    /// 10
+    Ten,
+}
+
+impl StoreWidget for ItemValue {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let new_s = options_str(self);
+        if settings_map
+            .get(key)
+            .is_some_and(|old_v| old_v.get_string().is_some_and(|old_s| old_s == new_s))
+        {
+            return false;
+        }
+        settings_map.insert(key, new_s);
+        true
+    }
+}
+
+impl ItemValue {
+    pub fn n(&self) -> i64 {
+        *self as i64
+    }
+
+    /// The option for the value `n`, if there is one
+    pub fn from_n(n: i64) -> Option<ItemValue> {
+        ItemValue::radio_button_options()
+            .into_iter()
+            .map(|o| o.value)
+            .find(|v| v.n() == n)
+    }
+}
+
+impl From<Split> for SplitEntry {
+    fn from(split: Split) -> Self {
+        SplitEntry {
+            split,
+            ..Default::default()
+        }
+    }
+}
+
+impl Widget for SplitEntry {
+    type Args = <Split as Widget>::Args;
+
+    fn register(key: &str, description: &str, args: Self::Args) -> Self {
+        let mut entry = SplitEntry::from(Split::register(key, description, args));
+        let key_take_item = format!("{}_take_item", key);
+        entry.take_item =
+            bool::register(&key_take_item, "Take Scene Data Item", BoolArgs::default());
+        set_tooltip(&key_take_item, TAKE_ITEM_TOOLTIP);
+        let key_value = format!("{}_value", key);
+        entry.value = ItemValue::register(&key_value, "Scene Data Value", ()).n() as i32;
+        let key_arm = format!("{}_arm", key);
+        entry.arm = bool::register(&key_arm, "Arm", BoolArgs::default());
+        let key_occurrence = format!("{}_occurrence", key);
+        entry.occurrence = Occurrence::register(&key_occurrence, "Occurrence", ());
+        let key_count_from = format!("{}_count_from", key);
+        entry.count_from = CountFrom::register(&key_count_from, "Count from", ());
+        let key_defer = format!("{}_defer", key);
+        entry.defer = Defer::register(&key_defer, "Split", ());
+        let key_delay = format!("{}_delay", key);
+        entry.delay_ms = Delay::register(&key_delay, "Delay", ()).ms();
+        let key_branch = format!("{}_branch", key);
+        entry.branch = Branch::register(&key_branch, "Branch", ());
+        entry
+    }
+
+    fn update_from(&mut self, settings_map: &asr::settings::Map, key: &str, args: Self::Args) {
+        self.split.update_from(settings_map, key, args);
+        self.scene = get_string_or_empty(settings_map, &format!("{}_scene", key));
+        self.id = get_string_or_empty(settings_map, &format!("{}_id", key));
+        self.take_item = get_bool_or_false(settings_map, &format!("{}_take_item", key));
+        self.value =
+            get_number_or_choice(settings_map, &format!("{}_value", key), ItemValue::n) as i32;
+        self.arm = get_bool_or_false(settings_map, &format!("{}_arm", key));
+        let key_occurrence = format!("{}_occurrence", key);
+        self.occurrence
+            .update_from(settings_map, &key_occurrence, ());
+        let key_count_from = format!("{}_count_from", key);
+        self.count_from
+            .update_from(settings_map, &key_count_from, ());
+        self.delay_ms = get_number_or_choice(settings_map, &format!("{}_delay", key), Delay::ms);
+        let key_defer = format!("{}_defer", key);
+        self.defer.update_from(settings_map, &key_defer, ());
+        let key_branch = format!("{}_branch", key);
+        self.branch.update_from(settings_map, &key_branch, ());
+    }
+}
+
+impl StoreWidget for SplitEntry {
+    fn insert_into(&self, settings_map: &asr::settings::Map, key: &str) -> bool {
+        let a = self.split.insert_into(settings_map, key);
+        let b = insert_string_if_changed(settings_map, &format!("{}_scene", key), &self.scene);
+        let c = insert_string_if_changed(settings_map, &format!("{}_id", key), &self.id);
+        let d = insert_number_or_choice(
+            settings_map,
+            &format!("{}_value", key),
+            self.value as i64,
+            ItemValue::from_n(self.value as i64),
+        );
+        let e = insert_bool_if_changed(settings_map, &format!("{}_arm", key), self.arm);
+        let key_take_item = format!("{}_take_item", key);
+        let k = insert_bool_if_changed(settings_map, &key_take_item, self.take_item);
+        if b || c {
+            set_tooltip(
+                &key_take_item,
+                &format!(
+                    "{}\nScene: {:?}, Id: {:?}",
+                    TAKE_ITEM_TOOLTIP, self.scene, self.id
+                ),
+            );
+        }
+        let f = self
+            .occurrence
+            .insert_into(settings_map, &format!("{}_occurrence", key));
+        let g = self
+            .count_from
+            .insert_into(settings_map, &format!("{}_count_from", key));
+        let h = self
+            .defer
+            .insert_into(settings_map, &format!("{}_defer", key));
+        let i = insert_number_or_choice(
+            settings_map,
+            &format!("{}_delay", key),
+            self.delay_ms,
+            Delay::from_ms(self.delay_ms),
+        );
+        let j = self
+            .branch
+            .insert_into(settings_map, &format!("{}_branch", key));
+        a || b || c || d || e || f || g || h || i || j || k
+    }
+}
+
+impl SplitEntry {
+    pub fn action(
+        &self,
+        prc: &Process,
+        g: &GameManagerFinder,
+        trans_now: bool,
+        ss: &mut SceneStore,
+        pds: &mut PlayerDataStore,
+        sds: &mut SceneDataStore,
+    ) -> SplitterAction {
+        self.evaluate(prc, g, trans_now, ss, pds, sds).action()
+    }
+
+    /// Like `action`, but also says which branches ran and what each returned
+    pub fn evaluate(
+        &self,
+        prc: &Process,
+        g: &GameManagerFinder,
+        trans_now: bool,
+        ss: &mut SceneStore,
+        pds: &mut PlayerDataStore,
+        sds: &mut SceneDataStore,
+    ) -> SplitEvaluation {
+        let a = match self.split {
+            Split::PersistentBoolItem => {
+                let s = &self.scene;
+                should_split(sds.persistent_bool_item_became_activated(prc, g, s, &self.id))
+            }
+            Split::PersistentIntItem => {
+                let s = &self.scene;
+                let v = self.value;
+                should_split(sds.persistent_int_item_reached(prc, g, s, &self.id, v))
+            }
+            _ => return splits::evaluate_splits(&self.split, prc, g, trans_now, ss, pds, sds),
+        };
+        if a != SplitterAction::Pass {
+            ss.split_this_transition = true;
+        }
+        SplitEvaluation::continuous(a)
+    }
+}
+
+/// Change tracking of its own for a split evaluated out of turn,
+/// such as a split ahead counting occurrences from the run start,
+/// so that it doesn't use up a change the current split is waiting to see.
+pub struct SplitStores {
+    pub pds: PlayerDataStore,
+    pub sds: SceneDataStore,
+}
+
+impl SplitStores {
+    pub fn new() -> SplitStores {
+        SplitStores {
+            pds: PlayerDataStore::new(),
+            sds: SceneDataStore::new(),
+        }
+    }
+
+    /// Forgets changes on a transition, like the current split's stores do
+    pub fn on_transition(&mut self, old_scene: &str) {
+        forget_on_transition(old_scene, &mut self.pds, &mut self.sds);
+    }
+
+    /// Forgets addresses in the old process, like the current split's stores do
+    pub fn detached(&mut self) {
+        self.sds.detached();
+    }
+}
+
+/// Forgets the changes that only count within a scene, on a transition from `old_scene`,
+/// or all of them when leaving the main menu for a save that may be a different file.
+pub fn forget_on_transition(old_scene: &str, pds: &mut PlayerDataStore, sds: &mut SceneDataStore) {
+    if old_scene == MENU_TITLE {
+        pds.reset();
+        sds.reset();
+    } else {
+        pds.clean_on_entry();
+    }
+}
+
+pub fn auto_reset_safe(entries: &[SplitEntry]) -> &'static [TimerState] {
+    let s: Vec<Split> = entries.iter().map(|e| e.split.clone()).collect();
+    splits::auto_reset_safe(&s)
+}
+
+const TAKE_ITEM_TOOLTIP: &str = "Takes the scene and id of the next Scene Data item \
+that changes in game, and the value it changes to, for Scene Data splits";
+
+fn get_string_or_empty(settings_map: &asr::settings::Map, key: &str) -> String {
+    settings_map
+        .get(key)
+        .and_then(|v| v.get_string())
+        .unwrap_or_default()
+}
+
+fn get_bool_or_false(settings_map: &asr::settings::Map, key: &str) -> bool {
+    settings_map
+        .get(key)
+        .and_then(|v| v.get_bool())
+        .unwrap_or_default()
+}
+
+fn get_i64_or_zero(settings_map: &asr::settings::Map, key: &str) -> i64 {
+    settings_map
+        .get(key)
+        .and_then(|v| v.get_i64())
+        .unwrap_or_default()
+}
+
+/// Reads a number that the settings GUI stores as a choice,
+/// or that a hand-edited splits file stores as a number
+fn get_number_or_choice<T: Widget + Default>(
+    settings_map: &asr::settings::Map,
+    key: &str,
+    number: fn(&T) -> i64,
+) -> i64 {
+    if let Some(n) = settings_map.get(key).and_then(|v| v.get_i64()) {
+        return n;
+    }
+    let mut choice = T::default();
+    choice.update_from(settings_map, key, Default::default());
+    number(&choice)
+}
+
+/// Inserts the number as a choice when the settings GUI has one for it,
+/// or as a number otherwise
+fn insert_number_or_choice<T: StoreWidget>(
+    settings_map: &asr::settings::Map,
+    key: &str,
+    n: i64,
+    choice: Option<T>,
+) -> bool {
+    match choice {
+        Some(choice) => choice.insert_into(settings_map, key),
+        None => insert_i64_if_changed(settings_map, key, n),
+    }
+}
+
+/// Inserts the string unless it's already there,
+/// or unless it's empty and there's nothing there.
+fn insert_string_if_changed(settings_map: &asr::settings::Map, key: &str, new_s: &str) -> bool {
+    let old_s = get_string_or_empty(settings_map, key);
+    if old_s == new_s {
+        return false;
+    }
+    settings_map.insert(key, new_s);
+    true
+}
+
+/// Inserts the integer unless it's already there,
+/// or unless it's zero and there's nothing there.
+fn insert_i64_if_changed(settings_map: &asr::settings::Map, key: &str, new_i: i64) -> bool {
+    let old_i = get_i64_or_zero(settings_map, key);
+    if old_i == new_i {
+        return false;
+    }
+    settings_map.insert(key, new_i);
+    true
+}
+
+/// Inserts the bool unless it's already there,
+/// or unless it's false and there's nothing there.
+fn insert_bool_if_changed(settings_map: &asr::settings::Map, key: &str, new_b: bool) -> bool {
+    let old_b = get_bool_or_false(settings_map, key);
+    if old_b == new_b {
+        return false;
+    }
+    settings_map.insert(key, new_b);
+    true
+}
@@ -0,0 +1,235 @@
+use crate::branches::Branches;
+use crate::deferred::DeferredSplit;
+use crate::diagnostics::WrappedAction;
+use crate::hollow_knight_memory::SceneStore;
+use crate::occurrence::OccurrenceCounter;
+use crate::split_arming::SplitArming;
+use crate::split_delay::SplitDelay;
+use crate::split_entry::{SplitEntry, SplitStores};
+use crate::timer::SplitterAction;
+
+/// What the wrappers around a split's condition look at, besides their own stores
+#[derive(Clone, Copy)]
+pub struct WrapperContext {
+    /// Whether every split is armed, not only the ones that ask for it
+    pub arm_splits: bool,
+    pub game_state: Option<i32>,
+    pub trans_now: bool,
+}
+
+/// The stores that the wrappers around a split's condition keep from tick to tick
+pub struct WrapperStores<'a> {
+    pub occurrences: &'a mut OccurrenceCounter,
+    pub arming: &'a mut SplitArming,
+    pub deferred: &'a mut DeferredSplit,
+    pub delay: &'a mut SplitDelay,
+    pub branches: &'a mut Branches,
+}
+
+/// Wraps the action `a` of the split at index `i` in its occurrence, arming, deferral, and delay.
+///
+/// When that passes, races the other alternatives of a branch starting at `i`,
+/// evaluating their first splits with `eval_alt` and wrapping them the same way,
+/// and takes the first one that splits.
+/// The timer still has to skip to the alternative taken, in `WrappedAction::branch`.
+///
+/// A split held back doesn't count as a split this transition,
+/// so `ss.split_this_transition` is put back unless something splits.
+pub fn wrap_action<F>(
+    cx: WrapperContext,
+    stores: WrapperStores,
+    splits: &[SplitEntry],
+    i: usize,
+    a: SplitterAction,
+    ss: &mut SceneStore,
+    mut eval_alt: F,
+) -> WrappedAction
+where
+    F: FnMut(&SplitEntry, &mut SceneStore, &mut SplitStores) -> SplitterAction,
+{
+    let split_this_transition = ss.split_this_transition;
+    // Holds a split back by its occurrence, arming, and deferral,
+    // the same way for the current split and the alternatives of a branch
+    let hold_back = |i: usize,
+                     e: &SplitEntry,
+                     a: SplitterAction,
+                     occurrences: &mut OccurrenceCounter,
+                     arming: &mut SplitArming,
+                     deferred: &mut DeferredSplit,
+                     ss: &mut SceneStore| {
+        let occurrence = occurrences.filter(i, e, a);
+        let armed = if 0 < i && (cx.arm_splits || e.arm) {
+            arming.filter(i, occurrence.clone(), cx.game_state, ss)
+        } else {
+            occurrence.clone()
+        };
+        let deferral = deferred.filter(i, e, armed.clone(), cx.trans_now, ss);
+        WrappedAction::held(occurrence, armed, deferral)
+    };
+    let e = &splits[i];
+    let mut wrapped = hold_back(
+        i,
+        e,
+        a,
+        stores.occurrences,
+        stores.arming,
+        stores.deferred,
+        ss,
+    );
+    wrapped.delay = stores.delay.filter(i, e, wrapped.deferral.clone());
+    wrapped.action = match wrapped.delay.clone() {
+        SplitterAction::Pass => {
+            ss.split_this_transition = split_this_transition;
+            let occurrences = stores.occurrences;
+            let taken = stores.branches.choose(i, splits, |e, alt| {
+                let a = eval_alt(e, ss, &mut alt.stores);
+                let a = hold_back(
+                    alt.start,
+                    e,
+                    a,
+                    occurrences,
+                    &mut alt.arming,
+                    &mut alt.deferred,
+                    ss,
+                );
+                ss.split_this_transition = split_this_transition;
+                a.deferral
+            });
+            wrapped.branch = taken;
+            match taken {
+                Some(j) => {
+                    let a = stores.delay.filter(j, &splits[j], SplitterAction::Split);
+                    if a == SplitterAction::Split {
+                        ss.split_this_transition = true;
+                    }
+                    a
+                }
+                None => SplitterAction::Pass,
+            }
+        }
+        a => a,
+    };
+    wrapped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::branches::Branch;
+    use crate::hollow_knight_memory::{GAME_STATE_MAIN_MENU, GAME_STATE_PLAYING};
+    use crate::occurrence::Occurrence;
+    use crate::timer::tests::timer;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    const PASS: SplitterAction = SplitterAction::Pass;
+    const SPLIT: SplitterAction = SplitterAction::Split;
+
+    fn scenes(old: &str, current: &str) -> SceneStore {
+        let mut ss = SceneStore::new();
+        ss.new_curr_scene_name(Some(old.to_string()));
+        ss.new_next_scene_name(Some(current.to_string()));
+        ss
+    }
+
+    /// The current split and the other alternative of a branch after the start split,
+    /// where the other alternative is armed, splits on its 2nd occurrence, and has a delay
+    fn branch_splits() -> Vec<SplitEntry> {
+        vec![
+            SplitEntry::default(),
+            SplitEntry {
+                branch: Branch::A,
+                ..Default::default()
+            },
+            SplitEntry {
+                branch: Branch::B,
+                arm: true,
+                occurrence: Occurrence::Second,
+                delay_ms: 1000,
+                ..Default::default()
+            },
+            SplitEntry::default(),
+        ]
+    }
+
+    struct Stores {
+        occurrences: OccurrenceCounter,
+        arming: SplitArming,
+        deferred: DeferredSplit,
+        delay: SplitDelay,
+        branches: Branches,
+    }
+
+    impl Stores {
+        fn new(splits: &[SplitEntry]) -> Stores {
+            let t = timer(splits.len());
+            let mut occurrences = OccurrenceCounter::new();
+            occurrences.update(&t, splits);
+            let mut branches = Branches::new(splits);
+            branches.update(&t);
+            Stores {
+                occurrences,
+                arming: SplitArming::new(),
+                deferred: DeferredSplit::new(),
+                delay: SplitDelay::new(splits),
+                branches,
+            }
+        }
+
+        /// Wraps the action `a` of the current split at index 1,
+        /// while the other alternative's condition is `alt`
+        fn tick(
+            &mut self,
+            splits: &[SplitEntry],
+            a: SplitterAction,
+            alt: SplitterAction,
+            game_state: i32,
+        ) -> WrappedAction {
+            let cx = WrapperContext {
+                arm_splits: false,
+                game_state: Some(game_state),
+                trans_now: false,
+            };
+            let stores = WrapperStores {
+                occurrences: &mut self.occurrences,
+                arming: &mut self.arming,
+                deferred: &mut self.deferred,
+                delay: &mut self.delay,
+                branches: &mut self.branches,
+            };
+            let mut ss = scenes("Fungus2_14", "Fungus2_15");
+            wrap_action(cx, stores, splits, 1, a, &mut ss, |_, _, _| alt.clone())
+        }
+    }
+
+    #[test]
+    fn passes_the_current_split_through() {
+        let splits = branch_splits();
+        let mut s = Stores::new(&splits);
+        let w = s.tick(&splits, SPLIT, SPLIT, GAME_STATE_PLAYING);
+        assert_eq!(w.action, SPLIT);
+        assert_eq!(w.branch, None);
+    }
+
+    #[test]
+    fn chains_arming_occurrence_and_delay_on_a_branch_alternative() {
+        let splits = branch_splits();
+        let mut s = Stores::new(&splits);
+        // the 1st occurrence doesn't split
+        let w = s.tick(&splits, PASS, SPLIT, GAME_STATE_MAIN_MENU);
+        assert_eq!((w.branch, w.action), (None, PASS));
+        let w = s.tick(&splits, PASS, PASS, GAME_STATE_MAIN_MENU);
+        assert_eq!((w.branch, w.action), (None, PASS));
+        // the 2nd occurrence isn't armed, since it hasn't passed while playing
+        let w = s.tick(&splits, PASS, SPLIT, GAME_STATE_MAIN_MENU);
+        assert_eq!((w.branch, w.action), (None, PASS));
+        let w = s.tick(&splits, PASS, PASS, GAME_STATE_PLAYING);
+        assert_eq!((w.branch, w.action), (None, PASS));
+        // armed, it takes the alternative, but waits on its delay
+        let w = s.tick(&splits, PASS, SPLIT, GAME_STATE_PLAYING);
+        assert_eq!(w.branch, Some(2));
+        assert_eq!(w.action, PASS);
+        assert!(s.delay.is_waiting(2));
+    }
+}
@@ -9,7 +9,10 @@ use asr::Process;
 use ugly_widget::radio_button::{options_str, RadioButtonOptions};
 use ugly_widget::store::StoreWidget;
 
+use crate::diagnostics::SplitEvaluation;
 use crate::hollow_knight_memory::*;
+use crate::log_level::log_enabled;
+use crate::settings_gui::LogLevel;
 use crate::timer::*;
 
 #[derive(Clone, Debug, Default, Eq, Gui, Ord, PartialEq, PartialOrd, RadioButtonOptions)]
@@ -2975,6 +2978,10 @@ pub enum Split {
     ///
     /// Splits when placing the flower at the grave of the Traitors' Child
     FlowerQuest,
+    /// Delicate Flower Broken (Event)
+    ///
+    /// Splits when the Delicate Flower breaks
+    FlowerBroken,
     /// Queen's Garden - Frogs (Transition)
     ///
     /// Splits on transition to QG frogs scene
@@ -3298,6 +3305,19 @@ pub enum Split {
     /// Splits when beating the Pantheon of Hallownest
     Pantheon5,
     // endregion: Godhome
+
+    // region: Scene Data
+    /// Persistent Bool Item (Scene Data)
+    ///
+    /// Splits when the persistent bool item with this split's scene and id becomes activated,
+    /// such as a lever, breakable wall, or one-way door
+    PersistentBoolItem,
+    /// Persistent Int Item (Scene Data)
+    ///
+    /// Splits when the persistent int item with this split's scene and id reaches or crosses this split's value,
+    /// such as a multi-state door, geo rock, or lifeblood cocoon
+    PersistentIntItem,
+    // endregion: Scene Data
 }
 
 impl StoreWidget for Split {
@@ -3926,7 +3946,7 @@ pub fn continuous_splits(
         Split::RandoWake => should_split(
             g.disable_pause(p).is_some_and(|d| !d)
                 && g.get_game_state(p).is_some_and(|s| s == GAME_STATE_PLAYING)
-                && g.get_scene_name(p).is_some_and(|s| !is_menu(&s)),
+                && g.get_scene_name_buf(p).is_some_and(|s| !s.is_menu()),
         ),
         Split::BenchAny => should_split(g.at_bench(p).is_some_and(|b| b)),
         Split::PlayerDeath => should_split(g.get_health(p).is_some_and(|h| h == 0)),
@@ -4147,47 +4167,57 @@ pub fn continuous_splits(
         Split::Mask5 => should_split(g.max_health_base(p).is_some_and(|h| h == 10)),
         Split::MaskShardMawlek => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_09"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_09")),
         ),
         Split::MaskShardGrubfather => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_38"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_38")),
         ),
         Split::MaskShardBretta => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Room_Bretta"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Room_Bretta")),
         ),
         Split::MaskShardQueensStation => should_split(
-            pds.obtained_mask_shard(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Fungus2_01"),
+            pds.obtained_mask_shard(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus2_01")),
         ),
         Split::MaskShardEnragedGuardian => should_split(
-            pds.obtained_mask_shard(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Mines_32"),
+            pds.obtained_mask_shard(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_32")),
         ),
         Split::MaskShardSeer => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p)
-                    .is_some_and(|s| s == "RestingGrounds_07"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("RestingGrounds_07")),
         ),
         Split::MaskShardGoam => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_13"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_13")),
         ),
         Split::MaskShardStoneSanctuary => should_split(
-            pds.obtained_mask_shard(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_36"),
+            pds.obtained_mask_shard(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_36")),
         ),
         Split::MaskShardWaterways => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Waterways_04b"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Waterways_04b")),
         ),
         Split::MaskShardFungalCore => should_split(
-            pds.obtained_mask_shard(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Fungus2_25"),
+            pds.obtained_mask_shard(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus2_25")),
         ),
         Split::MaskShardHive => should_split(
-            pds.obtained_mask_shard(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Hive_04"),
+            pds.obtained_mask_shard(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Hive_04")),
         ),
         Split::MaskShardFlower => should_split(
             pds.obtained_mask_shard(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Room_Mansion"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Room_Mansion")),
         ),
         // endregion: Masks and Mask Shards
         // region: Vessels and Vessel Fragments
@@ -4225,32 +4255,33 @@ pub fn continuous_splits(
         Split::Vessel3 => should_split(g.mp_reserve_max(p).is_some_and(|mp| mp == 99)),
         Split::VesselFragGreenpath => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_13"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_13")),
         ),
         Split::VesselFragCrossroadsLift => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_37"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_37")),
         ),
         Split::VesselFragKingsStation => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins2_09"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins2_09")),
         ),
         Split::VesselFragGarpedes => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_38"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Deepnest_38")),
         ),
         Split::VesselFragStagNest => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Cliffs_03"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Cliffs_03")),
         ),
         Split::VesselFragSeer => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p)
-                    .is_some_and(|s| s == "RestingGrounds_07"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("RestingGrounds_07")),
         ),
         Split::VesselFragFountain => should_split(
             pds.obtained_vessel_fragment(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Abyss_04"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Abyss_04")),
         ),
         // endregion: Vessels and Vessel Fragments
         // region: Charm Notches
@@ -4382,7 +4413,8 @@ pub fn continuous_splits(
         Split::DeepnestStation => should_split(g.opened_deepnest(p).is_some_and(|o| o)),
         Split::QueensGardensStation => should_split(g.opened_royal_gardens(p).is_some_and(|o| o)),
         Split::StagnestStation => should_split(
-            g.get_next_scene_name(p).is_some_and(|n| n == "Cliffs_03")
+            g.get_next_scene_name_buf(p)
+                .is_some_and(|n| n.is("Cliffs_03"))
                 && g.travelling(p).is_some_and(|t| t)
                 && g.opened_stag_nest(p).is_some_and(|o| o),
         ),
@@ -4396,18 +4428,18 @@ pub fn continuous_splits(
         Split::OnObtainHallownestSeal => should_split(pds.incremented_trinket2(p, g)),
         Split::SoulSanctumSeal => should_split(
             pds.incremented_trinket2(p, g)
-                && g.get_scene_name(p)
+                && g.get_scene_name_buf(p)
                     .is_some_and(|s| s.starts_with("Ruins1_32")),
         ),
         Split::OnObtainKingsIdol => should_split(pds.incremented_trinket3(p, g)),
         Split::GladeIdol => should_split(
             pds.incremented_trinket3(p, g)
-                && g.get_scene_name(p)
+                && g.get_scene_name_buf(p)
                     .is_some_and(|s| s.starts_with("RestingGrounds_08")),
         ),
         Split::DungDefenderIdol => should_split(
             pds.incremented_trinket3(p, g)
-                && g.get_scene_name(p)
+                && g.get_scene_name_buf(p)
                     .is_some_and(|s| s.starts_with("Waterways_15")),
         ),
         Split::ArcaneEgg8 => should_split(
@@ -4473,19 +4505,19 @@ pub fn continuous_splits(
         Split::OnObtainGrub => should_split(pds.incremented_grubs_collected(p, g)),
         Split::GrubBasinDive => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Abyss_17"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Abyss_17")),
         ),
         Split::GrubBasinWings => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Abyss_19"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Abyss_19")),
         ),
         Split::GrubCityBelowLoveTower => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins2_07"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins2_07")),
         ),
         Split::GrubCityBelowSanctum => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins1_05"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins1_05")),
         ),
         Split::GrubCityCollectorAll => should_split(
             g.scenes_grub_rescued(p)
@@ -4493,165 +4525,176 @@ pub fn continuous_splits(
         ),
         Split::GrubCityCollector => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins2_11"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins2_11")),
         ),
         Split::GrubCityGuardHouse => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins_House_01"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Ruins_House_01")),
         ),
         Split::GrubCitySanctum => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins1_32"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins1_32")),
         ),
         Split::GrubCitySpire => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins2_03"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins2_03")),
         ),
         Split::GrubCliffsBaldurShell => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_28"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_28")),
         ),
         Split::GrubCrossroadsAcid => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_35"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_35")),
         ),
         Split::GrubCrossroadsGuarded => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_48"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_48")),
         ),
         Split::GrubCrossroadsSpikes => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_31"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_31")),
         ),
         Split::GrubCrossroadsVengefly => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_05"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_05")),
         ),
         Split::GrubCrossroadsWall => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_03"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_03")),
         ),
         Split::GrubCrystalPeaksBottomLever => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_04"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_04")),
         ),
         Split::GrubCrystalPeaksCrown => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_24"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_24")),
         ),
         Split::GrubCrystalPeaksCrushers => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_19"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_19")),
         ),
         Split::GrubCrystalPeaksCrystalHeart => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_31"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_31")),
         ),
         Split::GrubCrystalPeaksMimics => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_16"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_16")),
         ),
         Split::GrubCrystalPeaksMound => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_35"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_35")),
         ),
         Split::GrubCrystalPeaksSpikes => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Mines_03"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Mines_03")),
         ),
         Split::GrubDeepnestBeastsDen => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p)
-                    .is_some_and(|s| s == "Deepnest_Spider_Town"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Deepnest_Spider_Town")),
         ),
         Split::GrubDeepnestDark => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_39"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Deepnest_39")),
         ),
         Split::GrubDeepnestMimics => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_36"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Deepnest_36")),
         ),
         Split::GrubDeepnestNosk => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_31"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Deepnest_31")),
         ),
         Split::GrubDeepnestSpikes => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_03"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Deepnest_03")),
         ),
         Split::GrubFogCanyonArchives => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_47"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_47")),
         ),
         Split::GrubFungalBouncy => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus2_18"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus2_18")),
         ),
         Split::GrubFungalSporeShroom => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus2_20"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus2_20")),
         ),
         Split::GrubGreenpathCornifer => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_06"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_06")),
         ),
         Split::GrubGreenpathHunter => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_07"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_07")),
         ),
         Split::GrubGreenpathMossKnight => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_21"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_21")),
         ),
         Split::GrubGreenpathVesselFragment => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_13"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_13")),
         ),
         Split::GrubHiveExternal => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Hive_03"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Hive_03")),
         ),
         Split::GrubHiveInternal => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Hive_04"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Hive_04")),
         ),
         Split::GrubKingdomsEdgeCenter => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_East_11"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Deepnest_East_11")),
         ),
         Split::GrubKingdomsEdgeOro => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Deepnest_East_14"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Deepnest_East_14")),
         ),
         Split::GrubQueensGardensBelowStag => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_10"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_10")),
         ),
         Split::GrubQueensGardensUpper => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_22"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_22")),
         ),
         Split::GrubQueensGardensWhiteLady => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_48"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_48")),
         ),
         Split::GrubRestingGroundsCrypts => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p)
-                    .is_some_and(|s| s == "RestingGrounds_10"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("RestingGrounds_10")),
         ),
         Split::GrubWaterwaysCenter => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Waterways_04"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Waterways_04")),
         ),
         Split::GrubWaterwaysHwurmps => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Waterways_14"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Waterways_14")),
         ),
         Split::GrubWaterwaysIsma => should_split(
             pds.incremented_grubs_collected(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Waterways_13"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Waterways_13")),
         ),
         Split::Mimic1 => should_split(g.kills_grub_mimic(p).is_some_and(|k| k == 4)),
         Split::Mimic2 => should_split(g.kills_grub_mimic(p).is_some_and(|k| k == 3)),
@@ -4746,35 +4789,38 @@ pub fn continuous_splits(
         ),
         Split::OnObtainGhostMarissa => should_split(
             pds.incremented_dream_orbs(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins_Bathhouse"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Ruins_Bathhouse")),
         ),
         Split::OnObtainGhostCaelifFera => should_split(
             pds.incremented_dream_orbs(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_24"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus1_24")),
         ),
         Split::OnObtainGhostPoggy => should_split(
             pds.incremented_dream_orbs(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins_Elevator"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Ruins_Elevator")),
         ),
         Split::OnObtainGhostGravedigger => should_split(
-            pds.incremented_dream_orbs(p, g) && g.get_scene_name(p).is_some_and(|s| s == "Town"),
+            pds.incremented_dream_orbs(p, g)
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Town")),
         ),
         Split::OnObtainGhostJoni => should_split(
             pds.incremented_dream_orbs(p, g)
-                && g.get_scene_name(p).is_some_and(|s| s == "Cliffs_05"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Cliffs_05")),
         ),
         // TODO: resolve possible confounding essence sources for Cloth, Vespa, and Revek
         Split::OnObtainGhostCloth => {
             let d = pds.traitor_lord_been_dead_for_a_tick(p, g);
             let o = pds.incremented_dream_orbs(p, g);
             // make sure both PlayerDataStore methods are evaluated before the `&&` so it doesn't short-circuit
-            should_split(d && o && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_23"))
+            should_split(d && o && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_23")))
         }
         Split::OnObtainGhostVespa => {
             let d = pds.hive_knight_been_dead_for_a_tick(p, g);
             let o = pds.incremented_dream_orbs(p, g);
             // make sure both PlayerDataStore methods are evaluated before the `&&` so it doesn't short-circuit
-            should_split(d && o && g.get_scene_name(p).is_some_and(|s| s == "Hive_05"))
+            should_split(d && o && g.get_scene_name_buf(p).is_some_and(|s| s.is("Hive_05")))
         }
         Split::OnObtainGhostRevek => {
             let (c, a, k) = sds.glade_ghosts_killed(p, g).unwrap_or_default();
@@ -4833,7 +4879,7 @@ pub fn continuous_splits(
         // region: Crossroads
         Split::ForgottenCrossroads => should_split(
             g.visited_crossroads(p).is_some_and(|v| v)
-                && g.get_scene_name(p)
+                && g.get_scene_name_buf(p)
                     .is_some_and(|s| s.starts_with("Crossroads_")),
         ),
         Split::InfectedCrossroads => should_split(
@@ -4845,7 +4891,8 @@ pub fn continuous_splits(
         Split::AspidHunter => should_split_skip(pds.aspid_hunter_arena(p, g)),
         Split::BenchCrossroadsStag => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Crossroads_47"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Crossroads_47")),
         ),
         Split::GruzMother => should_split(pds.killed_big_fly(p, g)),
         Split::SlyRescued => should_split(g.sly_rescued(p).is_some_and(|s| s)),
@@ -4877,7 +4924,8 @@ pub fn continuous_splits(
         }
         Split::BenchGreenpathStag => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus1_16_alt"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("Fungus1_16_alt")),
         ),
         Split::Hornet1 => should_split(g.hornet1_defeated(p).is_some_and(|k| k)),
         Split::Aluba => should_split(g.killed_lazy_flyer(p).is_some_and(|k| k)),
@@ -4897,7 +4945,7 @@ pub fn continuous_splits(
         Split::FungalWastes => should_split(g.visited_fungus(p).is_some_and(|v| v)),
         Split::BenchQueensStation => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus2_02"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus2_02")),
         ),
         Split::MushroomBrawler => should_split_skip(pds.mushroom_brawler_arena(p, g)),
         Split::ElderHu => should_split(g.killed_ghost_hu(p).is_some_and(|k| k)),
@@ -4923,8 +4971,8 @@ pub fn continuous_splits(
         Split::RestingGrounds => should_split(g.visited_resting_grounds(p).is_some_and(|v| v)),
         Split::BenchRGStag => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p)
-                    .is_some_and(|s| s == "RestingGrounds_09"),
+                && g.get_scene_name_buf(p)
+                    .is_some_and(|s| s.is("RestingGrounds_09")),
         ),
         Split::Xero => should_split(g.killed_ghost_xero(p).is_some_and(|k| k)),
         Split::XeroEssence => should_split(g.xero_defeated(p).is_some_and(|d| d == 2)),
@@ -4972,20 +5020,20 @@ pub fn continuous_splits(
         ),
         Split::BenchStorerooms => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins1_29"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins1_29")),
         ),
         Split::BenchKingsStation => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins2_08"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins2_08")),
         ),
         Split::GreatHuskSentry => should_split(g.killed_great_shield_zombie(p).is_some_and(|k| k)),
         Split::BenchSpire => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins1_18"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins1_18")),
         ),
         Split::BenchSpireGHS => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Ruins1_18")
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Ruins1_18"))
                 && g.kills_great_shield_zombie(p).is_some_and(|k| k < 10),
         ),
         Split::WatcherChandelier => should_split(g.watcher_chandelier(p).is_some_and(|c| c)),
@@ -5031,9 +5079,9 @@ pub fn continuous_splits(
         Split::WhiteDefenderStatueUnlocked => should_split(
             pds.dung_defender_awake_convo_on_entry(p, g)
                 && (g
-                    .get_scene_name(p)
+                    .get_scene_name_buf(p)
                     .is_some_and(|s| s.starts_with("Waterways_15"))
-                    || ss.all_scene_names().contains(&"Waterways_15"))
+                    || ss.is_scene_loaded("Waterways_15"))
                 && g.camera_target_destination(p)
                     .is_some_and(|v| 4.5 < v.x && v.x < 29.5 && -1.5 < v.y && v.y < 18.0),
         ),
@@ -5056,7 +5104,7 @@ pub fn continuous_splits(
         }
         Split::BenchHiddenStation => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Abyss_22"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Abyss_22")),
         ),
         Split::AbyssDoor => should_split(g.abyss_gate_opened(p).is_some_and(|o| o)),
         Split::AbyssLighthouse => should_split(g.abyss_lighthouse(p).is_some_and(|l| l)),
@@ -5068,7 +5116,7 @@ pub fn continuous_splits(
         Split::WhitePalaceOrb3 => should_split(g.white_palace_orb_3(p).is_some_and(|o| o)),
         Split::WhitePalaceOrb2 => should_split(g.white_palace_orb_2(p).is_some_and(|o| o)),
         Split::PathOfPainRoom4DDark => should_split(
-            g.get_scene_name(p)
+            g.get_scene_name_buf(p)
                 .is_some_and(|s| s.starts_with("White_Palace_20"))
                 && g.on_ground(p).is_some_and(|b| b)
                 && g.spell_quake(p).is_some_and(|b| b),
@@ -5195,11 +5243,12 @@ pub fn continuous_splits(
         Split::PetraArena => should_split_skip(pds.petra_arena(p, g)),
         Split::TollBenchQG => should_split(g.toll_bench_queens_gardens(p).is_some_and(|b| b)),
         Split::FlowerQuest => should_split(g.xun_flower_given(p).is_some_and(|g| g)),
+        Split::FlowerBroken => should_split(pds.flower_broken(p, g)),
         Split::Marmu => should_split(g.killed_ghost_marmu(p).is_some_and(|k| k)),
         Split::MarmuEssence => should_split(g.mum_caterpillar_defeated(p).is_some_and(|d| d == 2)),
         Split::BenchQGStag => should_split(
             g.at_bench(p).is_some_and(|b| b)
-                && g.get_scene_name(p).is_some_and(|s| s == "Fungus3_40"),
+                && g.get_scene_name_buf(p).is_some_and(|s| s.is("Fungus3_40")),
         ),
         Split::TraitorLord => should_split(g.killed_traitor_lord(p).is_some_and(|k| k)),
         Split::GivenWhiteLadyFlower => {
@@ -5248,27 +5297,40 @@ pub fn splits(
     pds: &mut PlayerDataStore,
     sds: &mut SceneDataStore,
 ) -> SplitterAction {
-    #[cfg(debug_assertions)]
-    pds.get_game_state(prc, g);
-    let a1 = continuous_splits(s, prc, g, ss, pds, sds).or_else(|| {
+    evaluate_splits(s, prc, g, trans_now, ss, pds, sds).action()
+}
+
+/// Like `splits`, but also says which branches ran and what each returned
+pub fn evaluate_splits(
+    s: &Split,
+    prc: &Process,
+    g: &GameManagerFinder,
+    trans_now: bool,
+    ss: &mut SceneStore,
+    pds: &mut PlayerDataStore,
+    sds: &mut SceneDataStore,
+) -> SplitEvaluation {
+    if log_enabled(LogLevel::Debug) {
+        pds.get_game_state(prc, g);
+    }
+    let mut e = SplitEvaluation::continuous(continuous_splits(s, prc, g, ss, pds, sds));
+    if e.continuous == SplitterAction::Pass {
         let pair = ss.pair();
-        let a2 = if !ss.split_this_transition {
-            transition_once_splits(s, &pair, prc, g, pds)
-        } else {
-            SplitterAction::Pass
-        };
-        a2.or_else(|| {
-            if trans_now {
-                transition_splits(s, &pair, prc, g, pds)
-            } else {
-                SplitterAction::Pass
-            }
-        })
-    });
-    if a1 != SplitterAction::Pass {
+        if !ss.split_this_transition {
+            e.transition_once = Some(transition_once_splits(s, &pair, prc, g, pds));
+        }
+        if e.transition_once
+            .as_ref()
+            .is_none_or(|a| *a == SplitterAction::Pass)
+            && trans_now
+        {
+            e.transition = Some(transition_splits(s, &pair, prc, g, pds));
+        }
+    }
+    if e.action() != SplitterAction::Pass {
         ss.split_this_transition = true;
     }
-    a1
+    e
 }
 
 fn starting_kings_pass(p: &Pair<&str>, prc: &Process, g: &GameManagerFinder) -> bool {
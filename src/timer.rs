@@ -1,5 +1,7 @@
 #[cfg(feature = "split-index")]
 use alloc::format;
+use alloc::vec::Vec;
+use core::cmp::max;
 
 use asr::timer::TimerState;
 
@@ -15,7 +17,6 @@ pub enum SplitterAction {
     Pass,
     Split,
     Skip,
-    #[allow(dead_code)]
     Reset,
     ManualSplit,
 }
@@ -82,6 +83,10 @@ pub struct Timer {
     /// Number of autosplits including both start and end.
     /// One more than the number of segments.
     n: usize,
+    /// Whether the move from index j to j+1 in this attempt was a skip.
+    /// Manual moves forward are counted as splits,
+    /// since the timer doesn't say whether they were splits or skips.
+    skipped: Vec<bool>,
     /// The set of timer states where it is safe to use auto-reset.
     auto_reset: &'static [TimerState],
     /// Whether GameTime has been initialized
@@ -94,6 +99,7 @@ impl Resettable for Timer {
         asr::timer::reset();
         self.state = TimerState::NotRunning;
         self.i = 0;
+        self.skipped.clear();
     }
 }
 
@@ -107,6 +113,7 @@ impl Timer {
             i: 0,
             last_split_index: asr_index.unwrap_or(-2),
             n,
+            skipped: Vec::new(),
             auto_reset,
             initialized_game_time: false,
         }
@@ -117,6 +124,14 @@ impl Timer {
         self.auto_reset = auto_reset;
     }
 
+    /// Restores the autosplit index of a run in progress, from a run snapshot
+    pub fn restore(&mut self, i: usize) {
+        if 0 < i && i < self.n {
+            self.i = i;
+            self.initialized_game_time = true;
+        }
+    }
+
     pub fn i(&self) -> usize {
         self.i
     }
@@ -125,6 +140,18 @@ impl Timer {
         self.n
     }
 
+    /// Whether the move from index j to j+1 in this attempt was a skip
+    pub fn skipped(&self, j: usize) -> bool {
+        self.skipped.get(j).copied().unwrap_or_default()
+    }
+
+    /// Records whether the move from the current index to the next was a skip
+    fn record_move(&mut self, skipped: bool) {
+        self.skipped
+            .resize(max(self.skipped.len(), self.i + 1), false);
+        self.skipped[self.i] = skipped;
+    }
+
     pub fn is_auto_reset_safe(&self) -> bool {
         self.auto_reset.contains(&self.state)
     }
@@ -149,6 +176,7 @@ impl Timer {
             // detect manual resets
             TimerState::NotRunning => {
                 self.i = 0;
+                self.skipped.clear();
                 r.reset();
                 asr::print_message("Detected a manual reset.");
                 self.initialized_game_time = false;
@@ -156,6 +184,7 @@ impl Timer {
             // detect manual starts
             TimerState::Running => {
                 if is_timer_state_between_runs(self.state) {
+                    self.skipped.clear();
                     self.i = 1;
                     r.reset();
                     asr::print_message("Detected a manual start.");
@@ -205,6 +234,10 @@ impl Timer {
             _ => (),
         }
         let new_i = (self.i as i32 + delta) as usize;
+        for j in self.i..new_i {
+            self.skipped.resize(max(self.skipped.len(), j + 1), false);
+            self.skipped[j] = false;
+        }
         if new_i >= self.n && self.is_auto_reset_safe() {
             self.i = 0;
         } else {
@@ -223,10 +256,12 @@ impl Timer {
             }
             SplitterAction::Skip => {
                 asr::timer::skip_split();
+                self.record_move(true);
                 self.i += 1;
             }
             SplitterAction::Split => {
                 if self.i == 0 {
+                    self.skipped.clear();
                     asr::timer::reset();
                     asr::timer::start();
                     r.reset();
@@ -240,10 +275,12 @@ impl Timer {
                 } else {
                     asr::timer::split();
                 }
+                self.record_move(false);
                 self.i += 1;
             }
             SplitterAction::ManualSplit => {
                 if self.last_split_index == -2 && 0 < self.i && self.i + 1 < self.n {
+                    self.record_move(false);
                     self.i += 1;
                 }
             }
@@ -260,3 +297,38 @@ impl Timer {
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    pub(crate) fn timer(n: usize) -> Timer {
+        Timer {
+            state: TimerState::Running,
+            last_state: TimerState::Running,
+            i: 1,
+            last_split_index: -2,
+            n,
+            skipped: Vec::new(),
+            auto_reset: &[],
+            initialized_game_time: true,
+        }
+    }
+
+    #[test]
+    fn skipped_records_each_move() {
+        let mut t = timer(5);
+        t.record_move(false);
+        t.i += 1;
+        t.record_move(true);
+        t.i += 1;
+        assert!(!t.skipped(1));
+        assert!(t.skipped(2));
+        // moves that haven't happened weren't skips
+        assert!(!t.skipped(3));
+        // undoing and splitting again replaces the skip
+        t.i = 2;
+        t.record_move(false);
+        assert!(!t.skipped(2));
+    }
+}